@@ -1,9 +1,24 @@
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
 use crate::annotation::Referent;
+use crate::cache::CacheKey;
+use crate::error::GeniusError;
 use crate::song::{Artist, SongPerformance};
 use crate::user::UserMetadata;
-use crate::Date;
+use crate::{Date, Genius, TextFormat};
+
+#[cfg(test)]
+mod test {
+    use crate::tests::{fake_genius, respond, ALBUM};
+    use crate::{Genius, TextFormat};
+
+    #[tokio::test]
+    async fn get_album_offline_test() {
+        let (genius, _) = fake_genius(Genius::builder(), vec![respond(200, ALBUM)]);
+        let album = genius.get_album(104_614, TextFormat::PLAIN).await.unwrap();
+        assert_eq!(album.name, "1000 Forms of Fear");
+    }
+}
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Album {
@@ -93,3 +108,19 @@ pub struct CoverArt {
     /// Page of the art.
     pub url: String,
 }
+
+impl Genius {
+    /// Get deeper information from a album by it's id, `text_format` is the format of text bodies related to the document, see [`TextFormat`].
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::NotFound`] if there is no album with this id, the other status errors are mapped as described in [`GeniusError`].
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON or the field `album` is missing, if this occurs you should contact the developer.
+    pub async fn get_album(&self, id: u32, text_format: TextFormat) -> Result<Album, GeniusError> {
+        let res = self.get(CacheKey::album(id, text_format).as_str()).await?;
+        res.album
+            .ok_or_else(|| GeniusError::ParseError("Album not found in data".to_owned()))
+    }
+}
@@ -1,8 +1,230 @@
-use serde::{Serialize, Deserialize};
+use futures_util::TryStreamExt;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::collections::BTreeMap as Map;
+use std::marker::PhantomData;
 
+use crate::auth::Scope;
+use crate::cache::CacheKey;
+use crate::client::Config;
+use crate::error::GeniusError;
+use crate::page::{self, Page, PageStream};
+use crate::transport::HttpRequest;
 use crate::user::{User, UserMetadata};
-use crate::Body;
+use crate::{Body, Genius, TextFormat};
+
+#[cfg(test)]
+mod test {
+    use crate::annotation::AnnotationInput;
+    use crate::auth::Scope;
+    use crate::cache::ResponseCache;
+    use crate::error::GeniusError;
+    use crate::retry::RetryPolicy;
+    use crate::tests::{fake_genius, respond, ANNOTATION, REFERENTS};
+    use crate::user::Vote;
+    use crate::{Genius, TextFormat};
+    use reqwest::header::CONTENT_TYPE;
+    use reqwest::Method;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn get_annotation_offline_test() {
+        let (genius, requests) = fake_genius(Genius::builder(), vec![respond(200, ANNOTATION)]);
+        let res = genius
+            .get_annotation(2_643_137, TextFormat::PLAIN)
+            .await
+            .unwrap();
+        assert_eq!(res.annotation.id, 2_643_137);
+        assert_eq!(res.annotation.cosigned_by.unwrap()[0].login, "genius");
+        assert_eq!(res.annotation.verified_by.unwrap().name, "Sia");
+        assert!(res.annotation.rejection_comment.is_none());
+        assert_eq!(res.referent.fragment, "Party girls don't get hurt");
+        assert!(res.referent.annotations.is_empty());
+        assert_eq!(
+            requests.lock().unwrap()[0].url,
+            "https://api.genius.com/annotations/2643137?text_format=plain"
+        );
+    }
+
+    #[tokio::test]
+    async fn annotation_write_offline_test() {
+        let (genius, requests) = fake_genius(
+            Genius::builder()
+                .scopes([Scope::CreateAnnotation])
+                .retry_policy(RetryPolicy::default().base_delay(Duration::from_millis(1))),
+            vec![
+                respond(200, ANNOTATION),
+                respond(503, ""),
+                respond(
+                    422,
+                    r#"{"meta":{"status":422},"response":{"errors":["Fragment can't be blank"]}}"#,
+                ),
+            ],
+        );
+        let input = AnnotationInput::new(
+            "Sia **swings**",
+            "https://example.com/chandelier",
+            "Party girls don't get hurt",
+        )
+        .before_html("<p>")
+        .title("Chandelier");
+        let annotation = genius.create_annotation(&input).await.unwrap();
+        assert_eq!(annotation.id, 2_643_137);
+        let request = requests.lock().unwrap()[0].clone();
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.url, "https://api.genius.com/annotations");
+        assert_eq!(request.headers[CONTENT_TYPE], "application/json");
+        let body: serde_json::Value = serde_json::from_slice(&request.body.unwrap()).unwrap();
+        assert_eq!(body["annotation"]["body"]["markdown"], "Sia **swings**");
+        assert_eq!(
+            body["referent"]["context_for_display"]["before_html"],
+            "<p>"
+        );
+        assert_eq!(body["web_page"]["title"], "Chandelier");
+
+        assert!(matches!(
+            genius.create_annotation(&input).await,
+            Err(GeniusError::InternalServerError(_))
+        ));
+        assert!(matches!(
+            genius.create_annotation(&input).await,
+            Err(GeniusError::Validation { errors, .. }) if errors == ["Fragment can't be blank"]
+        ));
+        assert!(matches!(
+            genius.delete_annotation(2_643_137).await,
+            Err(GeniusError::MissingScope(Scope::ManageAnnotation))
+        ));
+        assert_eq!(requests.lock().unwrap().len(), 3);
+
+        let (genius, requests) = fake_genius(Genius::builder(), vec![respond(204, "")]);
+        genius.delete_annotation(2_643_137).await.unwrap();
+        let request = requests.lock().unwrap()[0].clone();
+        assert_eq!(request.method, Method::DELETE);
+        assert_eq!(request.url, "https://api.genius.com/annotations/2643137");
+    }
+
+    #[tokio::test]
+    async fn annotation_write_cache_offline_test() {
+        let (genius, requests) = fake_genius(
+            Genius::builder()
+                .scopes([Scope::ManageAnnotation])
+                .cache(ResponseCache::new(10, Duration::from_secs(30))),
+            vec![
+                respond(200, ANNOTATION),
+                respond(200, ANNOTATION),
+                respond(200, ANNOTATION),
+                respond(200, ANNOTATION),
+                respond(204, ""),
+                respond(404, ""),
+            ],
+        );
+        let input = AnnotationInput::new("Sia", "https://example.com/chandelier", "Party");
+        for format in [TextFormat::PLAIN, TextFormat::PLAIN | TextFormat::HTML] {
+            genius.get_annotation(2_643_137, format).await.unwrap();
+        }
+        genius.update_annotation(2_643_137, &input).await.unwrap();
+        genius
+            .get_annotation(2_643_137, TextFormat::PLAIN)
+            .await
+            .unwrap();
+        genius.delete_annotation(2_643_137).await.unwrap();
+        assert!(matches!(
+            genius.get_annotation(2_643_137, TextFormat::PLAIN).await,
+            Err(GeniusError::NotFound(_))
+        ));
+        assert_eq!(requests.lock().unwrap().len(), 6);
+    }
+
+    #[tokio::test]
+    async fn vote_offline_test() {
+        let mut voted: serde_json::Value = serde_json::from_str(ANNOTATION).unwrap();
+        let annotation = &mut voted["response"]["annotation"];
+        annotation["votes_total"] = 86.into();
+        annotation["current_user_metadata"]["interactions"] = serde_json::json!({ "vote": "up" });
+        let voted = voted.to_string();
+        let (genius, requests) = fake_genius(
+            Genius::builder().scopes([Scope::Vote]),
+            vec![respond(200, &voted)],
+        );
+        let annotation = genius.upvote_annotation(2_643_137).await.unwrap();
+        assert_eq!(annotation.votes_total, Some(86));
+        assert_eq!(
+            annotation.current_user_metadata.interactions.unwrap().vote,
+            Some(Vote::Up)
+        );
+        let request = requests.lock().unwrap()[0].clone();
+        assert_eq!(request.method, Method::PUT);
+        assert_eq!(
+            request.url,
+            "https://api.genius.com/annotations/2643137/upvote"
+        );
+
+        let (genius, requests) = fake_genius(
+            Genius::builder().cache(ResponseCache::new(10, Duration::from_secs(30))),
+            vec![
+                respond(200, ANNOTATION),
+                respond(200, &voted),
+                respond(200, &voted),
+            ],
+        );
+        let before = genius
+            .get_annotation(2_643_137, TextFormat::PLAIN)
+            .await
+            .unwrap();
+        genius.upvote_annotation(2_643_137).await.unwrap();
+        let after = genius
+            .get_annotation(2_643_137, TextFormat::PLAIN)
+            .await
+            .unwrap();
+        assert_ne!(before.annotation.votes_total, Some(86));
+        assert_eq!(after.annotation.votes_total, Some(86));
+        assert_eq!(requests.lock().unwrap().len(), 3);
+
+        let (genius, _) = fake_genius(Genius::builder().scopes([Scope::Me]), vec![]);
+        assert!(matches!(
+            genius.unvote_annotation(2_643_137).await,
+            Err(GeniusError::MissingScope(Scope::Vote))
+        ));
+    }
+
+    #[tokio::test]
+    async fn referents_offline_test() {
+        let (genius, requests) = fake_genius(
+            Genius::builder(),
+            vec![respond(200, REFERENTS), respond(200, REFERENTS)],
+        );
+        let referents = genius
+            .referents()
+            .song_id(378_195)
+            .created_by_id(1)
+            .text_format(TextFormat::PLAIN)
+            .per_page(1)
+            .limit(2)
+            .collect_all()
+            .await
+            .unwrap();
+        assert_eq!(referents.len(), 2);
+        assert_eq!(referents[0].fragment, "Party girls don't get hurt");
+        assert_eq!(referents[0].annotations[0].id, 2_643_137);
+        assert_eq!(
+            referents[0].annotatable.as_ref().unwrap().title,
+            "Chandelier"
+        );
+        assert_eq!(
+            requests.lock().unwrap()[1].url,
+            "https://api.genius.com/referents?created_by_id=1&song_id=378195&text_format=plain&per_page=1&page=2"
+        );
+
+        let (genius, requests) = fake_genius(Genius::builder(), vec![respond(200, REFERENTS)]);
+        let referents = genius.referents().web_page_id(10_347).collect_all().await;
+        assert_eq!(referents.unwrap().len(), 1);
+        assert_eq!(
+            requests.lock().unwrap()[0].url,
+            "https://api.genius.com/referents?web_page_id=10347&page=1"
+        );
+    }
+}
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Referent {
@@ -25,9 +247,17 @@ pub struct Referent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub song_id: Option<u32>,
     pub url: String,
+    /// Ids of the verified artists who annotated this referent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified_annotator_ids: Option<Vec<u32>>,
+    /// Song or web page of this referent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotatable: Option<Annotatable>,
     /// > Only with `user-core` level token
     #[serde(skip_serializing_if = "Option::is_none")]
     pub current_user_metadata: Option<UserMetadata>,
+    /// > Not in `get_annotation`, the referent is then returned with the annotation
+    #[serde(default)]
     pub annotations: Vec<Annotation>,
 }
 
@@ -38,15 +268,37 @@ pub struct Annotation {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment_count: Option<u32>,
     pub community: bool,
+    /// Users who cosigned this annotation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cosigned_by: Option<Vec<User>>,
+    /// Preview of the body chosen by the author.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_preview: Option<String>,
+    /// If this annotation was deleted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<bool>,
     pub has_voters: bool,
     pub id: u32,
+    /// If this annotation needs to be explained further.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub needs_exegesis: Option<bool>,
     pub pinned: bool,
+    /// Why this annotation was rejected, only when its `state` is `rejected`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejection_comment: Option<String>,
     pub share_url: String,
+    /// Source cited by the author.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Value>,
     pub state: String,
     pub url: String,
     pub verified: bool,
+    /// Verified artist who verified this annotation.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub votes_total: Option<u32>,
+    pub verified_by: Option<User>,
+    /// Upvotes minus downvotes, negative when there are more downvotes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub votes_total: Option<i32>,
     pub current_user_metadata: UserMetadata,
     pub authors: Vec<AnnotationAuthor>,
 }
@@ -56,3 +308,487 @@ pub struct AnnotationAuthor {
     pub attribution: f32,
     pub user: User,
 }
+
+/// Annotation returned by [`Genius::get_annotation`] with the referent it explains.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AnnotationWithReferent {
+    pub annotation: Annotation,
+    /// Referent with the fragment of the document explained by the annotation.
+    pub referent: Referent,
+}
+
+/// Document of a [`Referent`], a song or a web page.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Annotatable {
+    pub api_path: String,
+    /// Artist of the song or domain of the web page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    pub id: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    pub link_title: String,
+    pub title: String,
+    /// `Song` or `WebPage`.
+    #[serde(rename = "type")]
+    pub annotatable_type: String,
+    pub url: String,
+}
+
+/// [`Referents`] state without a song or web page filter.
+#[derive(Debug, Clone, Copy)]
+pub struct AnyDocument;
+
+/// [`Referents`] state filtered by song.
+#[derive(Debug, Clone, Copy)]
+pub struct SongDocument;
+
+/// [`Referents`] state filtered by web page.
+#[derive(Debug, Clone, Copy)]
+pub struct WebPageDocument;
+
+/// Request of the referents and their annotations, created with [`Genius::referents`].
+///
+/// The referents can be filtered by song or by web page but not by both, which is checked by the compiler:
+/// ```compile_fail,E0599
+/// # let genius = genius_rust::Genius::new("my_token".to_owned());
+/// genius.referents().song_id(378195).web_page_id(10347);
+/// ```
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::{Genius, TextFormat};
+///
+/// # async fn run() -> Result<(), genius_rust::error::GeniusError> {
+/// let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+/// let referents = genius
+///     .referents()
+///     .song_id(378195)
+///     .text_format(TextFormat::PLAIN)
+///     .collect_all()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Referents<D = AnyDocument> {
+    genius: Genius,
+    document: Option<(&'static str, u32)>,
+    created_by_id: Option<u32>,
+    text_format: Option<TextFormat>,
+    per_page: Option<u32>,
+    page: u32,
+    limit: Option<usize>,
+    filter: PhantomData<D>,
+}
+
+impl Referents {
+    pub(crate) const fn new(genius: Genius) -> Self {
+        Self {
+            genius,
+            document: None,
+            created_by_id: None,
+            text_format: None,
+            per_page: None,
+            page: 1,
+            limit: None,
+            filter: PhantomData,
+        }
+    }
+
+    /// Only the referents of the song `song_id`.
+    #[must_use]
+    pub fn song_id(self, song_id: u32) -> Referents<SongDocument> {
+        self.document("song_id", song_id)
+    }
+
+    /// Only the referents of the web page `web_page_id`.
+    #[must_use]
+    pub fn web_page_id(self, web_page_id: u32) -> Referents<WebPageDocument> {
+        self.document("web_page_id", web_page_id)
+    }
+
+    fn document<D>(self, name: &'static str, id: u32) -> Referents<D> {
+        Referents {
+            genius: self.genius,
+            document: Some((name, id)),
+            created_by_id: self.created_by_id,
+            text_format: self.text_format,
+            per_page: self.per_page,
+            page: self.page,
+            limit: self.limit,
+            filter: PhantomData,
+        }
+    }
+}
+
+impl<D> Referents<D> {
+    /// Only the referents created by the user `created_by_id`.
+    #[must_use]
+    pub const fn created_by_id(mut self, created_by_id: u32) -> Self {
+        self.created_by_id = Some(created_by_id);
+        self
+    }
+
+    /// Format of the annotation bodies, see [`TextFormat`].
+    #[must_use]
+    pub const fn text_format(mut self, text_format: TextFormat) -> Self {
+        self.text_format = Some(text_format);
+        self
+    }
+
+    /// Number of referents requested per page, Genius defaults to 20 and allows at most 50, `per_page` is clamped to
+    /// `1..=50`.
+    #[must_use]
+    pub const fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(page::clamp_per_page(per_page, 50));
+        self
+    }
+
+    /// First page requested, defaults to 1.
+    #[must_use]
+    pub const fn page(mut self, page: u32) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Stop after `limit` referents, by default all the pages are requested.
+    #[must_use]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Stream of the referents with their annotations, the pages are requested until one is not full.
+    ///
+    /// A failed request is returned as the last item, see [`crate::Genius::get_song`] for the errors.
+    #[must_use]
+    pub fn stream(self) -> PageStream<Referent> {
+        let query = [
+            self.created_by_id.map(|id| format!("created_by_id={id}")),
+            self.document.map(|(name, id)| format!("{name}={id}")),
+            self.text_format
+                .map(|format| format!("text_format={format}")),
+            self.per_page.map(|per_page| format!("per_page={per_page}")),
+        ];
+        let query = query.iter().flatten().cloned().collect::<Vec<_>>();
+        let per_page = self.per_page.unwrap_or(20) as usize;
+        let genius = self.genius;
+        page::paginate(self.page, self.limit, move |page| {
+            let genius = genius.clone();
+            let path = page::page_path("/referents", &query, page);
+            async move {
+                let referents = genius.get(&path).await?.referents.ok_or_else(|| {
+                    GeniusError::ParseError("Referents not found in data".to_owned())
+                })?;
+                let next_page = (referents.len() >= per_page).then_some(page + 1);
+                Ok(Page {
+                    items: referents,
+                    next_page,
+                })
+            }
+        })
+    }
+
+    /// Request all the pages and collect the referents.
+    ///
+    /// # Errors
+    ///
+    /// The first error of the [`Self::stream`].
+    pub async fn collect_all(self) -> Result<Vec<Referent>, GeniusError> {
+        self.stream().try_collect().await
+    }
+}
+
+/// Content of an annotation created with [`Genius::create_annotation`] or updated with [`Genius::update_annotation`].
+///
+/// The annotated fragment is found in the web page at `raw_annotatable_url`, `before_html` and `after_html` are the
+/// text around it when the fragment is not unique in the page.
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::{annotation::AnnotationInput, auth::Scope, Genius};
+///
+/// # async fn run() -> Result<(), genius_rust::error::GeniusError> {
+/// let genius = Genius::builder()
+///     .token("my_token")
+///     .scopes([Scope::CreateAnnotation])
+///     .build();
+/// let input = AnnotationInput::new(
+///     "A **chandelier** is a branched light fixture.",
+///     "https://example.com/chandelier",
+///     "swing from the chandelier",
+/// )
+/// .title("Chandelier");
+/// let annotation = genius.create_annotation(&input).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnnotationInput {
+    markdown: String,
+    raw_annotatable_url: String,
+    fragment: String,
+    before_html: Option<String>,
+    after_html: Option<String>,
+    canonical_url: Option<String>,
+    og_url: Option<String>,
+    title: Option<String>,
+}
+
+impl AnnotationInput {
+    /// Annotation with the body `markdown` on the `fragment` of the web page at `raw_annotatable_url`.
+    #[must_use]
+    pub fn new(
+        markdown: impl Into<String>,
+        raw_annotatable_url: impl Into<String>,
+        fragment: impl Into<String>,
+    ) -> Self {
+        Self {
+            markdown: markdown.into(),
+            raw_annotatable_url: raw_annotatable_url.into(),
+            fragment: fragment.into(),
+            before_html: None,
+            after_html: None,
+            canonical_url: None,
+            og_url: None,
+            title: None,
+        }
+    }
+
+    /// HTML just before the fragment in the page.
+    #[must_use]
+    pub fn before_html(mut self, before_html: impl Into<String>) -> Self {
+        self.before_html = Some(before_html.into());
+        self
+    }
+
+    /// HTML just after the fragment in the page.
+    #[must_use]
+    pub fn after_html(mut self, after_html: impl Into<String>) -> Self {
+        self.after_html = Some(after_html.into());
+        self
+    }
+
+    /// `<link rel="canonical">` URL of the page.
+    #[must_use]
+    pub fn canonical_url(mut self, canonical_url: impl Into<String>) -> Self {
+        self.canonical_url = Some(canonical_url.into());
+        self
+    }
+
+    /// `<meta property="og:url">` URL of the page.
+    #[must_use]
+    pub fn og_url(mut self, og_url: impl Into<String>) -> Self {
+        self.og_url = Some(og_url.into());
+        self
+    }
+
+    /// Title of the page.
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "annotation": {
+                "body": { "markdown": self.markdown },
+            },
+            "referent": {
+                "raw_annotatable_url": self.raw_annotatable_url,
+                "fragment": self.fragment,
+                "context_for_display": {
+                    "before_html": self.before_html,
+                    "after_html": self.after_html,
+                },
+            },
+            "web_page": {
+                "canonical_url": self.canonical_url,
+                "og_url": self.og_url,
+                "title": self.title,
+            },
+        })
+    }
+}
+
+/// `POST /annotations` request of `input`.
+pub(crate) fn create_request(
+    config: &Config,
+    input: &AnnotationInput,
+) -> Result<HttpRequest, GeniusError> {
+    config.scoped_request(
+        Method::POST,
+        "/annotations",
+        Scope::CreateAnnotation,
+        Some(&input.to_json()),
+    )
+}
+
+/// `PUT /annotations/:id` request of `input`.
+pub(crate) fn update_request(
+    config: &Config,
+    id: u32,
+    input: &AnnotationInput,
+) -> Result<HttpRequest, GeniusError> {
+    config.scoped_request(
+        Method::PUT,
+        &format!("/annotations/{id}"),
+        Scope::ManageAnnotation,
+        Some(&input.to_json()),
+    )
+}
+
+/// `PUT /annotations/:id/<action>` request of a vote.
+pub(crate) fn vote_request(
+    config: &Config,
+    id: u32,
+    action: &str,
+) -> Result<HttpRequest, GeniusError> {
+    config.scoped_request::<Value>(
+        Method::PUT,
+        &format!("/annotations/{id}/{action}"),
+        Scope::Vote,
+        None,
+    )
+}
+
+/// `DELETE /annotations/:id` request.
+pub(crate) fn delete_request(config: &Config, id: u32) -> Result<HttpRequest, GeniusError> {
+    config.scoped_request::<Value>(
+        Method::DELETE,
+        &format!("/annotations/{id}"),
+        Scope::ManageAnnotation,
+        None,
+    )
+}
+
+impl Genius {
+    /// Get an annotation by it's id with the referent it explains, `text_format` is the format of the annotation body, see [`TextFormat`].
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::NotFound`] if there is no annotation with this id, the other status errors are mapped as described in [`GeniusError`].
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON or the field `annotation` or `referent` is missing, if this occurs you should contact the developer.
+    pub async fn get_annotation(
+        &self,
+        id: u32,
+        text_format: TextFormat,
+    ) -> Result<AnnotationWithReferent, GeniusError> {
+        let res = self
+            .get(CacheKey::annotation(id, text_format).as_str())
+            .await?;
+        res.annotation_with_referent()
+    }
+
+    /// Referents with their annotations, filtered by song, web page or creator, see [`Referents`].
+    #[must_use]
+    pub fn referents(&self) -> Referents {
+        Referents::new(self.clone())
+    }
+
+    /// Create an annotation on a web page, the token must have the `create_annotation` scope.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::MissingScope`] if the scopes given to [`crate::GeniusBuilder::scopes`] don't have
+    /// `create_annotation`.
+    /// Will return [`GeniusError::Validation`] if Genius rejects the content, with the message of each invalid field.
+    /// Will return [`GeniusError::RequestError`] if the request fails, the other status errors are mapped as described
+    /// in [`GeniusError`].
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON or the field `annotation` is missing.
+    pub async fn create_annotation(
+        &self,
+        input: &AnnotationInput,
+    ) -> Result<Annotation, GeniusError> {
+        let request = create_request(&self.inner.config, input)?;
+        self.send_uncached(request, "/annotations")
+            .await?
+            .annotation()
+    }
+
+    /// Replace the content of the annotation `id`, the token must have the `manage_annotation` scope. The cached
+    /// responses of [`Self::get_annotation`] for this annotation are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::create_annotation`] with the `manage_annotation` scope.
+    /// Will return [`GeniusError::NotFound`] if there is no annotation with this id.
+    pub async fn update_annotation(
+        &self,
+        id: u32,
+        input: &AnnotationInput,
+    ) -> Result<Annotation, GeniusError> {
+        let request = update_request(&self.inner.config, id, input)?;
+        let res = self
+            .send_uncached(request, &format!("/annotations/{id}"))
+            .await?;
+        self.inner.config.invalidate_annotation(id);
+        res.annotation()
+    }
+
+    /// Delete the annotation `id`, the token must have the `manage_annotation` scope. The cached responses of
+    /// [`Self::get_annotation`] for this annotation are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::MissingScope`] if the scopes given to [`crate::GeniusBuilder::scopes`] don't have
+    /// `manage_annotation`.
+    /// Will return [`GeniusError::NotFound`] if there is no annotation with this id.
+    /// Will return [`GeniusError::RequestError`] if the request fails, the other status errors are mapped as described
+    /// in [`GeniusError`].
+    pub async fn delete_annotation(&self, id: u32) -> Result<(), GeniusError> {
+        let request = delete_request(&self.inner.config, id)?;
+        self.send(request, &format!("/annotations/{id}")).await?;
+        self.inner.config.invalidate_annotation(id);
+        Ok(())
+    }
+
+    /// Upvote the annotation `id` and return it with the new `votes_total`, the token must have the `vote` scope.
+    /// The cached responses of [`Self::get_annotation`] for this annotation are dropped, cached referents listing it
+    /// keep the old votes until they expire.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::MissingScope`] with [`Scope::Vote`] if the scopes given to
+    /// [`crate::GeniusBuilder::scopes`] don't have `vote`.
+    /// Will return [`GeniusError::NotFound`] if there is no annotation with this id.
+    /// Will return [`GeniusError::RequestError`] if the request fails, the other status errors are mapped as described
+    /// in [`GeniusError`].
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON or the field `annotation` is missing.
+    pub async fn upvote_annotation(&self, id: u32) -> Result<Annotation, GeniusError> {
+        self.vote(id, "upvote").await
+    }
+
+    /// Downvote the annotation `id` and return it with the new `votes_total`, the token must have the `vote` scope.
+    /// The cache is updated like with [`Self::upvote_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::upvote_annotation`].
+    pub async fn downvote_annotation(&self, id: u32) -> Result<Annotation, GeniusError> {
+        self.vote(id, "downvote").await
+    }
+
+    /// Remove the vote on the annotation `id` and return it with the new `votes_total`, the token must have the
+    /// `vote` scope. The cache is updated like with [`Self::upvote_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::upvote_annotation`].
+    pub async fn unvote_annotation(&self, id: u32) -> Result<Annotation, GeniusError> {
+        self.vote(id, "unvote").await
+    }
+
+    async fn vote(&self, id: u32, action: &str) -> Result<Annotation, GeniusError> {
+        let request = vote_request(&self.inner.config, id, action)?;
+        let res = self
+            .send_uncached(request, &format!("/annotations/{id}/{action}"))
+            .await?;
+        self.inner.config.invalidate_annotation(id);
+        res.annotation()
+    }
+}
@@ -0,0 +1,292 @@
+use futures_util::TryStreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::annotation::Referent;
+use crate::cache::CacheKey;
+use crate::error::GeniusError;
+use crate::page::{self, Page, PageStream};
+use crate::song::Song;
+use crate::user::UserMetadata;
+use crate::{Body, Genius, TextFormat};
+
+#[cfg(test)]
+mod test {
+    use crate::artist::SongSort;
+    use crate::tests::{fake_genius, respond, urls, ARTIST, ARTIST_SONGS};
+    use crate::{Genius, TextFormat};
+
+    #[tokio::test]
+    async fn get_artist_offline_test() {
+        let (genius, requests) = fake_genius(Genius::builder(), vec![respond(200, ARTIST)]);
+        let artist = genius.get_artist(16_775, TextFormat::PLAIN).await.unwrap();
+        assert_eq!(artist.name, "Sia");
+        assert_eq!(artist.alternate_names.unwrap(), ["Sia Furler"]);
+        assert_eq!(artist.twitter_name.as_deref(), Some("Sia"));
+        assert_eq!(artist.followers_count, Some(5231));
+        assert_eq!(artist.description_annotation.unwrap().id, 16_775);
+        assert_eq!(
+            requests.lock().unwrap()[0].url,
+            "https://api.genius.com/artists/16775?text_format=plain"
+        );
+    }
+
+    #[tokio::test]
+    async fn artist_songs_offline_test() {
+        let last_page = ARTIST_SONGS.replace(r#""next_page": 2"#, r#""next_page": null"#);
+        let (genius, requests) = fake_genius(
+            Genius::builder(),
+            vec![respond(200, ARTIST_SONGS), respond(200, &last_page)],
+        );
+        let songs = genius
+            .artist_songs(16_775)
+            .sort(SongSort::Popularity)
+            .per_page(2)
+            .collect_all()
+            .await
+            .unwrap();
+        let titles = songs.iter().map(|song| &song.title).collect::<Vec<_>>();
+        assert_eq!(
+            titles,
+            ["Chandelier", "Elastic Heart", "Chandelier", "Elastic Heart"]
+        );
+        let urls = urls(&requests);
+        assert_eq!(
+            urls,
+            [
+                "https://api.genius.com/artists/16775/songs?sort=popularity&per_page=2&page=1",
+                "https://api.genius.com/artists/16775/songs?sort=popularity&per_page=2&page=2",
+            ]
+        );
+
+        let (genius, requests) = fake_genius(Genius::builder(), vec![respond(200, ARTIST_SONGS)]);
+        let songs = genius
+            .artist_songs(16_775)
+            .page(2)
+            .limit(1)
+            .collect_all()
+            .await;
+        assert_eq!(songs.unwrap().len(), 1);
+        assert_eq!(
+            requests.lock().unwrap()[0].url,
+            "https://api.genius.com/artists/16775/songs?page=2"
+        );
+    }
+}
+
+/// Artist of a song or album, the fields only in `get_artist` are set when it comes from [`crate::Genius::get_artist`].
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Artist {
+    /// Other names of the artist.
+    /// > Only in `get_artist`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternate_names: Option<Vec<String>>,
+    /// Path of the API.
+    pub api_path: String,
+    /// Description of the artist.
+    /// > Only in `get_artist`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Body>,
+    /// Name of the artist on facebook.
+    /// > Only in `get_artist`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facebook_name: Option<String>,
+    /// Number of users following this artist.
+    /// > Only in `get_artist`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub followers_count: Option<u32>,
+    /// Artist header image.
+    pub header_image_url: String,
+    /// Artist id.
+    pub id: u32,
+    /// Artist image.
+    pub image_url: String,
+    /// First letter of the artist name.
+    /// > Only with `user-core` level token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_character: Option<char>,
+    /// Name of the artist on instagram.
+    /// > Only in `get_artist`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instagram_name: Option<String>,
+    /// Is this artist a meme?
+    pub is_meme_verified: bool,
+    /// If this artist is verified.
+    pub is_verified: bool,
+    /// Name of the artist.
+    pub name: String,
+    /// > Only with `user-core` level token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// If this artist is a translation page.
+    /// > Only in `get_artist`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translation_artist: Option<bool>,
+    /// Name of the artist on twitter.
+    /// > Only in `get_artist`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub twitter_name: Option<String>,
+    /// Url of the artist page.
+    pub url: String,
+    /// How much iq this artist has.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iq: Option<u32>,
+    /// Permissions and interactions of the current user with this artist.
+    /// > Only in `get_artist`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_user_metadata: Option<UserMetadata>,
+    /// Annotation of the description.
+    /// > Only in `get_artist`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_annotation: Option<Referent>,
+}
+
+/// Order of the songs of [`ArtistSongs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SongSort {
+    /// Alphabetical order of the titles, the default.
+    Title,
+    /// Most viewed songs first.
+    Popularity,
+}
+
+impl SongSort {
+    /// Value of the `sort` parameter.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Title => "title",
+            Self::Popularity => "popularity",
+        }
+    }
+}
+
+/// Request of the songs of an artist, created with [`Genius::artist_songs`].
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::{artist::SongSort, Genius};
+///
+/// # async fn run() -> Result<(), genius_rust::error::GeniusError> {
+/// let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+/// let songs = genius
+///     .artist_songs(16775)
+///     .sort(SongSort::Popularity)
+///     .limit(10)
+///     .collect_all()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct ArtistSongs {
+    genius: Genius,
+    artist_id: u32,
+    sort: Option<SongSort>,
+    per_page: Option<u32>,
+    page: u32,
+    limit: Option<usize>,
+}
+
+impl ArtistSongs {
+    pub(crate) const fn new(genius: Genius, artist_id: u32) -> Self {
+        Self {
+            genius,
+            artist_id,
+            sort: None,
+            per_page: None,
+            page: 1,
+            limit: None,
+        }
+    }
+
+    /// Order of the songs, defaults to [`SongSort::Title`].
+    #[must_use]
+    pub const fn sort(mut self, sort: SongSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Number of songs requested per page, Genius defaults to 20 and allows at most 50, `per_page` is clamped to
+    /// `1..=50`.
+    #[must_use]
+    pub const fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(page::clamp_per_page(per_page, 50));
+        self
+    }
+
+    /// First page requested, defaults to 1.
+    #[must_use]
+    pub const fn page(mut self, page: u32) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Stop after `limit` songs, by default all the pages are requested.
+    #[must_use]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Stream of the songs, following the `next_page` of each response.
+    ///
+    /// A failed request is returned as the last item, see [`crate::Genius::get_song`] for the errors.
+    #[must_use]
+    pub fn stream(self) -> PageStream<Song> {
+        let query = [
+            self.sort.map(|sort| format!("sort={}", sort.as_str())),
+            self.per_page.map(|per_page| format!("per_page={per_page}")),
+        ];
+        let query = query.iter().flatten().cloned().collect::<Vec<_>>();
+        let path = format!("/artists/{}/songs", self.artist_id);
+        let genius = self.genius;
+        page::paginate(self.page, self.limit, move |page| {
+            let genius = genius.clone();
+            let path = page::page_path(&path, &query, page);
+            async move {
+                let res = genius.get(&path).await?;
+                Ok(Page {
+                    items: res.songs.ok_or_else(|| {
+                        GeniusError::ParseError("Songs not found in data".to_owned())
+                    })?,
+                    next_page: res.next_page,
+                })
+            }
+        })
+    }
+
+    /// Request all the pages and collect the songs.
+    ///
+    /// # Errors
+    ///
+    /// The first error of the [`Self::stream`].
+    pub async fn collect_all(self) -> Result<Vec<Song>, GeniusError> {
+        self.stream().try_collect().await
+    }
+}
+
+impl Genius {
+    /// Get deeper information from an artist by it's id, `text_format` is the format of text bodies related to the document, see [`TextFormat`].
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::NotFound`] if there is no artist with this id, the other status errors are mapped as described in [`GeniusError`].
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON or the field `artist` is missing, if this occurs you should contact the developer.
+    pub async fn get_artist(
+        &self,
+        id: u32,
+        text_format: TextFormat,
+    ) -> Result<Artist, GeniusError> {
+        let res = self.get(CacheKey::artist(id, text_format).as_str()).await?;
+        res.artist
+            .ok_or_else(|| GeniusError::ParseError("Artist not found in data".to_owned()))
+    }
+
+    /// Songs of an artist by it's id, see [`ArtistSongs`] for the sort order, the pages and the stream of songs.
+    #[must_use]
+    pub fn artist_songs(&self, artist_id: u32) -> ArtistSongs {
+        ArtistSongs::new(self.clone(), artist_id)
+    }
+}
@@ -1,21 +1,35 @@
-use reqwest::{Client, Url};
+use reqwest::{Method, Url};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::client::{self, Config};
+use crate::error::GeniusError;
+use crate::transport::{HttpRequest, HttpResponse, TransportError};
+use crate::{builder, Genius};
 
 #[cfg(test)]
 mod test {
     use crate::auth::auth_url;
+    use crate::Genius;
     #[test]
     fn auth_url_test() {
         let url = auth_url("my_client_id", "code", None, Some("me vote"), None);
         assert_eq!("https://api.genius.com/oauth/authorize?client_id=my_client_id&response_type=code&scope=me+vote", url.as_str());
     }
+
+    #[test]
+    fn auth_url_with_custom_api_url_test() {
+        let genius = Genius::builder().api_url("http://localhost:8080/").build();
+        let url = genius.auth_url("my_client_id", "token", None, None, Some("xyz"));
+        assert_eq!("http://localhost:8080/oauth/authorize?client_id=my_client_id&response_type=token&state=xyz", url.as_str());
+    }
 }
 
 /// Authentication by login.
 pub mod login;
 
 #[derive(Serialize)]
-struct AuthRequest {
+pub(crate) struct AuthRequest {
     code: String,
     client_secret: String,
     client_id: String,
@@ -24,6 +38,38 @@ struct AuthRequest {
     grant_type: String,
 }
 
+/// Permission given to a token by the user, requested with the `scope` of [`auth_url`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// Read the account of the user with [`crate::Genius::account`].
+    Me,
+    /// Create annotations with [`crate::Genius::create_annotation`].
+    CreateAnnotation,
+    /// Update and delete the annotations of the user.
+    ManageAnnotation,
+    /// Vote on annotations with [`crate::Genius::upvote_annotation`].
+    Vote,
+}
+
+impl Scope {
+    /// Name of the scope in the `scope` parameter of [`auth_url`].
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Me => "me",
+            Self::CreateAnnotation => "create_annotation",
+            Self::ManageAnnotation => "manage_annotation",
+            Self::Vote => "vote",
+        }
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Authentication response.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AuthResponse {
@@ -46,10 +92,14 @@ pub struct AuthResponse {
 /// #### Examples
 /// Basic usage:
 /// ```
-/// use genius_rs::auth::auth_url;
+/// use genius_rust::auth::auth_url;
 ///
 /// let auth_url = auth_url("my_client_id", "code", None, Some("me vote"), None);
 /// ```
+///
+/// # Panics
+///
+/// If the formatted URL is not valid.
 #[must_use]
 pub fn auth_url(
     client_id: &str,
@@ -57,6 +107,98 @@ pub fn auth_url(
     redirect_uri: Option<&str>,
     scope: Option<&str>,
     state: Option<&str>,
+) -> Url {
+    format_auth_url(
+        builder::API_URL,
+        client_id,
+        response_type,
+        redirect_uri,
+        scope,
+        state,
+    )
+}
+
+/// Transform the `code` in a token, the result is [`AuthResponse`]. `code` expires so be very light on this operation. The response token will be level `client`.
+///
+/// `client_secret`, `client_id` and `redirect_uri` are found at <https://genius.com/api-clients>.
+///
+/// # Errors
+///
+/// Will return [`GeniusError::RequestError`] if the request fails.
+/// Will return [`GeniusError::ParseError`] if the response is not valid JSON.
+/// An invalid code is not an error, the response will have `error` and `error_description` instead of `access_token`.
+pub async fn authenticate(
+    code: String,
+    client_secret: String,
+    client_id: String,
+    redirect_uri: String,
+) -> Result<AuthResponse, GeniusError> {
+    let auth_req = code_auth_body(code, client_secret, client_id, redirect_uri);
+    request_token(&Genius::builder().build(), &auth_req).await
+}
+
+impl Genius {
+    /// Same as [`auth_url`] but using the API URL of this client.
+    ///
+    /// # Panics
+    ///
+    /// If the formatted URL is not valid.
+    #[must_use]
+    pub fn auth_url(
+        &self,
+        client_id: &str,
+        response_type: &str,
+        redirect_uri: Option<&str>,
+        scope: Option<&str>,
+        state: Option<&str>,
+    ) -> Url {
+        format_auth_url(
+            &self.inner.config.api_url,
+            client_id,
+            response_type,
+            redirect_uri,
+            scope,
+            state,
+        )
+    }
+
+    /// Same as [`authenticate`] but going through this client.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`authenticate`].
+    pub async fn authenticate(
+        &self,
+        code: String,
+        client_secret: String,
+        client_id: String,
+        redirect_uri: String,
+    ) -> Result<AuthResponse, GeniusError> {
+        let auth_req = code_auth_body(code, client_secret, client_id, redirect_uri);
+        request_token(self, &auth_req).await
+    }
+
+    /// Same as [`login::login_with_username`] but going through this client.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`login::login_with_username`].
+    pub async fn login_with_username(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<AuthResponse, GeniusError> {
+        request_token(self, &login::username_auth_body(username, password)).await
+    }
+}
+
+pub(crate) fn format_auth_url(
+    api_url: &str,
+    client_id: &str,
+    response_type: &str,
+    redirect_uri: Option<&str>,
+    scope: Option<&str>,
+    state: Option<&str>,
 ) -> Url {
     let mut params = vec![("client_id", client_id), ("response_type", response_type)];
     if let Some(redirect_uri) = redirect_uri {
@@ -68,35 +210,50 @@ pub fn auth_url(
     if let Some(state) = state {
         params.push(("state", state));
     }
-    Url::parse_with_params("https://api.genius.com/oauth/authorize", params)
+    Url::parse_with_params(&format!("{api_url}/oauth/authorize"), params)
         .expect("Can't parse authentication URL.")
 }
 
-/// Transform the `code` in a token, the result is [`AuthResponse`]. `code` expires so be very light on this operation. The response token will be level `client`.
-///
-/// `client_secret`, `client_id` and `redirect_uri` are found at <https://genius.com/api-clients>.
-///
-/// # Errors
-///
-/// If the code is not valid or the request fails.
-pub async fn authenticate(
+pub(crate) fn code_auth_body(
     code: String,
     client_secret: String,
     client_id: String,
     redirect_uri: String,
-) -> Result<AuthResponse, reqwest::Error> {
-    let auth_req = AuthRequest {
+) -> AuthRequest {
+    AuthRequest {
         code,
         client_secret,
         client_id,
         redirect_uri,
         response_type: "code".to_owned(),
         grant_type: "authorization_code".to_owned(),
-    };
-    let url = Url::parse("https://api.genius.com/oauth/token")
-        .expect("Could not parse valid URL from login_with_username input.");
-    let client = Client::new();
-    let request = client.post(url).json(&auth_req).send().await?;
-    let result = request.json::<AuthResponse>().await?;
-    Ok(result)
+    }
+}
+
+/// Exchange `body` for a token at the `oauth/token` endpoint of `genius`.
+pub(crate) async fn request_token<T: Serialize + Sync>(
+    genius: &Genius,
+    body: &T,
+) -> Result<AuthResponse, GeniusError> {
+    let request = token_request(&genius.inner.config, body)?;
+    parse_token(genius.inner.transport.send(request).await)
+}
+
+/// `POST` of `body` to the `oauth/token` endpoint.
+pub(crate) fn token_request<T: Serialize>(
+    config: &Config,
+    body: &T,
+) -> Result<HttpRequest, GeniusError> {
+    let mut request = config.request(Method::POST, &format!("{}/oauth/token", config.api_url));
+    client::set_json_body(&mut request, body)?;
+    Ok(request)
+}
+
+/// Parse the response of a [`token_request`] whatever its status, the errors are in the [`AuthResponse`].
+pub(crate) fn parse_token(
+    outcome: Result<HttpResponse, TransportError>,
+) -> Result<AuthResponse, GeniusError> {
+    let response = outcome.map_err(|e| GeniusError::RequestError(e.to_string()))?;
+    serde_json::from_slice::<AuthResponse>(&response.body)
+        .map_err(|e| GeniusError::ParseError(e.to_string()))
 }
@@ -1,11 +1,12 @@
 use base64::decode;
 use chrono::offset::Utc;
 use hmac::{Hmac, Mac};
-use reqwest::{Client, Url};
 use serde::Serialize;
 use sha2::Sha256;
 
-use crate::auth::AuthResponse;
+use crate::auth::{request_token, AuthResponse};
+use crate::error::GeniusError;
+use crate::Genius;
 
 #[cfg(test)]
 mod test {
@@ -24,7 +25,7 @@ mod test {
 }
 
 #[derive(Serialize)]
-struct AuthLoginRequest {
+pub(crate) struct AuthLoginRequest {
     password: String,
     username: String,
     client_id: String,
@@ -37,9 +38,9 @@ struct AuthLoginRequest {
 /// #### Examples
 /// Basic usage:
 /// ```
-/// use genius_rs::auth::login;
+/// use genius_rust::auth::login;
 ///
-/// # async fn run() -> Result<(), reqwest::Error> {
+/// # async fn run() -> Result<(), genius_rust::error::GeniusError> {
 /// let auth = login::login_with_username("username", "password")
 ///     .await
 ///     .expect("Error requesting login with username");
@@ -54,26 +55,22 @@ struct AuthLoginRequest {
 ///
 /// # Errors
 ///
-/// If the username or password is incorrect.
+/// Will return [`GeniusError::RequestError`] if the request fails.
+/// Will return [`GeniusError::ParseError`] if the response is not valid JSON.
+/// An incorrect username or password is not an error, the response will have `error` and `error_description` instead of `access_token`.
+///
+/// # Panics
+///
+/// If the embedded client credentials can't be decoded.
 pub async fn login_with_username(
     username: &str,
     password: &str,
-) -> Result<AuthResponse, reqwest::Error> {
-    let url = Url::parse("https://api.genius.com/oauth/token")
-        .expect("Could not parse valid URL from login_with_username input.");
+) -> Result<AuthResponse, GeniusError> {
     let auth_request = username_auth_body(username, password);
-    let client = Client::new();
-    let res: AuthResponse = client
-        .post(url)
-        .json(&auth_request)
-        .send()
-        .await?
-        .json()
-        .await?;
-    Ok(res)
+    request_token(&Genius::builder().build(), &auth_request).await
 }
 
-fn username_auth_body(username: &str, password: &str) -> AuthLoginRequest {
+pub(crate) fn username_auth_body(username: &str, password: &str) -> AuthLoginRequest {
     let timestamp = Utc::now().timestamp().to_string();
     let grant_type = "password".to_owned();
     let long_id_hist =
@@ -97,6 +94,6 @@ fn digest(username: &str, timestamp: &str) -> String {
         &decode("ZEVWWVpfcDVzX0tHY3Y0UGJJN015LWpjdXBhMHdWcTZJT081S1BqSzBKNjI2cXozWVA4OVphS1BTS3VHVDZONkQ1eTN1ZXc4WGVicnk4YmZXWkt5Rnc=")
             .expect("Unable to decode the key.")
     ).expect("An error occurred loading the key.");
-    mac.update(format!("{}{}", username, timestamp).as_bytes());
+    mac.update(format!("{username}{timestamp}").as_bytes());
     format!("{:x}", mac.finalize().into_bytes())
 }
@@ -0,0 +1,519 @@
+use reqwest::blocking::Client;
+use reqwest::Url;
+use std::sync::Arc;
+
+use crate::album::Album;
+use crate::annotation::{self, Annotation, AnnotationInput, AnnotationWithReferent};
+use crate::artist::Artist;
+use crate::auth::{self, login, AuthResponse};
+use crate::cache::{CacheKey, ResponseCache};
+use crate::client::{self, Config, Lookup};
+use crate::disk_cache::DiskCache;
+use crate::error::GeniusError;
+use crate::links;
+use crate::lyrics::{self, Lyrics, LyricsSource};
+use crate::search::{Hit, MultiSearch};
+use crate::song::Song;
+use crate::transport::{self, HttpRequest, HttpResponse, TransportError};
+use crate::user::{self, CurrentUser};
+use crate::web_page::{self, WebPage, WebPageLookup};
+use crate::{BlobResponse, GeniusBuilder, TextFormat};
+
+#[cfg(test)]
+mod test {
+    use crate::blocking::Genius;
+    use crate::error::GeniusError;
+    use crate::tests::{SEARCH, SONG};
+    use crate::TextFormat;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serve `responses` in order on a local port, returning its base URL and the received request lines.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut lines = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                lines.push(line.trim_end().to_owned());
+                let mut length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim_end().is_empty() {
+                        break;
+                    }
+                    if let Some(value) = header.to_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                reader
+                    .by_ref()
+                    .take(length)
+                    .read_to_end(&mut Vec::new())
+                    .unwrap();
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            lines
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn blocking_offline_test() {
+        let (url, server) = serve(vec![
+            (200, SEARCH),
+            (200, SONG),
+            (404, r#"{"meta":{"status":404,"message":"Not found"}}"#),
+            (200, r#"{"access_token":"abc"}"#),
+        ]);
+        let genius = Genius::builder()
+            .token("token")
+            .api_url(&url)
+            .build_blocking();
+        let hits = genius.search("Sia").unwrap();
+        assert_eq!(hits[0].result.full_title, "Chandelier by Sia");
+        assert_eq!(
+            genius.get_song(378_195, TextFormat::PLAIN).unwrap().id,
+            378_195
+        );
+        assert!(matches!(
+            genius.get_album(1, TextFormat::PLAIN),
+            Err(GeniusError::NotFound(_))
+        ));
+        let auth = genius
+            .authenticate(
+                "code".to_owned(),
+                "secret".to_owned(),
+                "id".to_owned(),
+                "uri".to_owned(),
+            )
+            .unwrap();
+        assert_eq!(auth.access_token.as_deref(), Some("abc"));
+        assert_eq!(
+            server.join().unwrap(),
+            [
+                "GET /search?q=Sia HTTP/1.1",
+                "GET /songs/378195?text_format=plain HTTP/1.1",
+                "GET /albums/1?text_format=plain HTTP/1.1",
+                "POST /oauth/token HTTP/1.1",
+            ]
+        );
+    }
+}
+
+/// Blocking version of [`crate::Genius`] built on [`reqwest::blocking`], created with
+/// [`GeniusBuilder::build_blocking`].
+///
+/// It has the methods of the async client returning a single response, only the requests block the current thread,
+/// so it must not be used inside an async runtime. The paginated requests [`crate::Genius::artist_songs`],
+/// [`crate::Genius::referents`], [`crate::Genius::search_query`], [`crate::Genius::web_page_referents`] and
+/// [`crate::Genius::get_annotated_lyrics`] are only available on the async client.
+///
+/// It uses the same configuration except [`GeniusBuilder::transport`] and [`GeniusBuilder::client`], use
+/// [`GeniusBuilder::blocking_client`] to configure its HTTP client.
+/// #### Examples
+/// Basic usage:
+/// ```no_run
+/// use genius_rust::blocking::Genius;
+///
+/// let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+/// let response = genius.search("Ariana Grande").unwrap();
+/// println!("{}", response[0].result.full_title);
+/// ```
+#[derive(Clone)]
+pub struct Genius {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    client: Client,
+    config: Config,
+}
+
+impl Genius {
+    /// Same as [`crate::Genius::new`].
+    #[must_use]
+    pub fn new(token: String) -> Self {
+        Self::builder().token(token).build_blocking()
+    }
+
+    /// Configure a client, see [`GeniusBuilder`] and [`GeniusBuilder::build_blocking`].
+    #[must_use]
+    pub fn builder() -> GeniusBuilder {
+        GeniusBuilder::new()
+    }
+
+    pub(crate) fn new_with(client: Client, config: Config) -> Self {
+        Self {
+            inner: Arc::new(Inner { client, config }),
+        }
+    }
+
+    /// Same as [`crate::Genius::search`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::search`].
+    pub fn search(&self, q: &str) -> Result<Vec<Hit>, GeniusError> {
+        self.get(CacheKey::search(q).as_str())?.hits()
+    }
+
+    /// Same as [`crate::Genius::search_multi`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::search_multi`].
+    pub fn search_multi(&self, q: &str) -> Result<MultiSearch, GeniusError> {
+        self.get(CacheKey::search_multi(q).as_str())?.multi_search()
+    }
+
+    /// Same as [`crate::Genius::get_lyrics`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::get_lyrics`].
+    pub fn get_lyrics(&self, id: u32) -> Result<Lyrics, GeniusError> {
+        let song = self.get_song(id, TextFormat::PLAIN)?;
+        if let Some(lyrics) = lyrics::api_lyrics(&song, self.inner.config.lyrics_strategy)? {
+            return Ok(lyrics);
+        }
+        let url = links::on_web_url(&song.url, &self.inner.config.web_url);
+        let lines = lyrics::parse_page(&self.get_page(&url)?)?;
+        Ok(Lyrics::new(lines, &song, LyricsSource::Page))
+    }
+
+    /// Same as [`crate::Genius::resolve_song_id`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::resolve_song_id`].
+    pub fn resolve_song_id(&self, url: &str) -> Result<u32, GeniusError> {
+        let url = links::page_url(url, &self.inner.config.web_url)?;
+        links::page_song_id(&self.get_page(&url)?)
+    }
+
+    /// Same as [`crate::Genius::get_song_by_url`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::get_song_by_url`].
+    pub fn get_song_by_url(&self, url: &str, text_format: TextFormat) -> Result<Song, GeniusError> {
+        let id = self.resolve_song_id(url)?;
+        self.get_song(id, text_format)
+    }
+
+    /// Same as [`crate::Genius::get_lyrics_by_url`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::get_lyrics_by_url`].
+    pub fn get_lyrics_by_url(&self, url: &str) -> Result<Lyrics, GeniusError> {
+        let page = self.get_page(&links::page_url(url, &self.inner.config.web_url)?)?;
+        let song = self.get_song(links::page_song_id(&page)?, TextFormat::PLAIN)?;
+        if let Some(lyrics) = lyrics::api_lyrics(&song, self.inner.config.lyrics_strategy)? {
+            return Ok(lyrics);
+        }
+        Ok(Lyrics::new(
+            lyrics::parse_page(&page)?,
+            &song,
+            LyricsSource::Page,
+        ))
+    }
+
+    /// Same as [`crate::Genius::get_song`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::get_song`].
+    pub fn get_song(&self, id: u32, text_format: TextFormat) -> Result<Song, GeniusError> {
+        let res = self.get(CacheKey::song(id, text_format).as_str())?;
+        res.song
+            .ok_or_else(|| GeniusError::ParseError("Song not found in data".to_owned()))
+    }
+
+    /// Same as [`crate::Genius::get_album`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::get_album`].
+    pub fn get_album(&self, id: u32, text_format: TextFormat) -> Result<Album, GeniusError> {
+        let res = self.get(CacheKey::album(id, text_format).as_str())?;
+        res.album
+            .ok_or_else(|| GeniusError::ParseError("Album not found in data".to_owned()))
+    }
+
+    /// Same as [`crate::Genius::get_artist`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::get_artist`].
+    pub fn get_artist(&self, id: u32, text_format: TextFormat) -> Result<Artist, GeniusError> {
+        let res = self.get(CacheKey::artist(id, text_format).as_str())?;
+        res.artist
+            .ok_or_else(|| GeniusError::ParseError("Artist not found in data".to_owned()))
+    }
+
+    /// Same as [`crate::Genius::lookup_web_page`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::lookup_web_page`].
+    pub fn lookup_web_page(&self, lookup: &WebPageLookup) -> Result<WebPage, GeniusError> {
+        self.get(web_page::lookup_key(lookup)?.as_str())?.web_page()
+    }
+
+    /// Same as [`crate::Genius::get_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::get_annotation`].
+    pub fn get_annotation(
+        &self,
+        id: u32,
+        text_format: TextFormat,
+    ) -> Result<AnnotationWithReferent, GeniusError> {
+        self.get(CacheKey::annotation(id, text_format).as_str())?
+            .annotation_with_referent()
+    }
+
+    /// Same as [`crate::Genius::create_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::create_annotation`].
+    pub fn create_annotation(&self, input: &AnnotationInput) -> Result<Annotation, GeniusError> {
+        let request = annotation::create_request(&self.inner.config, input)?;
+        self.send_uncached(&request, "/annotations")?.annotation()
+    }
+
+    /// Same as [`crate::Genius::update_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::update_annotation`].
+    pub fn update_annotation(
+        &self,
+        id: u32,
+        input: &AnnotationInput,
+    ) -> Result<Annotation, GeniusError> {
+        let request = annotation::update_request(&self.inner.config, id, input)?;
+        let res = self.send_uncached(&request, &format!("/annotations/{id}"))?;
+        self.inner.config.invalidate_annotation(id);
+        res.annotation()
+    }
+
+    /// Same as [`crate::Genius::delete_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::delete_annotation`].
+    pub fn delete_annotation(&self, id: u32) -> Result<(), GeniusError> {
+        let request = annotation::delete_request(&self.inner.config, id)?;
+        self.send(&request, &format!("/annotations/{id}"))?;
+        self.inner.config.invalidate_annotation(id);
+        Ok(())
+    }
+
+    /// Same as [`crate::Genius::account`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::account`].
+    pub fn account(&self) -> Result<CurrentUser, GeniusError> {
+        let request = user::account_request(&self.inner.config)?;
+        self.send_uncached(&request, "/account")?.user()
+    }
+
+    /// Same as [`crate::Genius::upvote_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::upvote_annotation`].
+    pub fn upvote_annotation(&self, id: u32) -> Result<Annotation, GeniusError> {
+        self.vote(id, "upvote")
+    }
+
+    /// Same as [`crate::Genius::downvote_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::downvote_annotation`].
+    pub fn downvote_annotation(&self, id: u32) -> Result<Annotation, GeniusError> {
+        self.vote(id, "downvote")
+    }
+
+    /// Same as [`crate::Genius::unvote_annotation`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::Genius::unvote_annotation`].
+    pub fn unvote_annotation(&self, id: u32) -> Result<Annotation, GeniusError> {
+        self.vote(id, "unvote")
+    }
+
+    /// Same as [`crate::auth::auth_url`] but using the API URL of this client.
+    ///
+    /// # Panics
+    ///
+    /// If the formatted URL is not valid.
+    #[must_use]
+    pub fn auth_url(
+        &self,
+        client_id: &str,
+        response_type: &str,
+        redirect_uri: Option<&str>,
+        scope: Option<&str>,
+        state: Option<&str>,
+    ) -> Url {
+        auth::format_auth_url(
+            &self.inner.config.api_url,
+            client_id,
+            response_type,
+            redirect_uri,
+            scope,
+            state,
+        )
+    }
+
+    /// Same as [`crate::auth::authenticate`] but going through this client.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::auth::authenticate`].
+    pub fn authenticate(
+        &self,
+        code: String,
+        client_secret: String,
+        client_id: String,
+        redirect_uri: String,
+    ) -> Result<AuthResponse, GeniusError> {
+        let auth_req = auth::code_auth_body(code, client_secret, client_id, redirect_uri);
+        let request = auth::token_request(&self.inner.config, &auth_req)?;
+        auth::parse_token(self.execute(request))
+    }
+
+    /// Same as [`crate::auth::login::login_with_username`] but going through this client.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`crate::auth::login::login_with_username`].
+    pub fn login_with_username(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<AuthResponse, GeniusError> {
+        let auth_req = login::username_auth_body(username, password);
+        let request = auth::token_request(&self.inner.config, &auth_req)?;
+        auth::parse_token(self.execute(request))
+    }
+
+    /// The response cache configured with [`GeniusBuilder::cache`].
+    #[must_use]
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.inner.config.cache.as_ref()
+    }
+
+    /// The on-disk cache configured with [`GeniusBuilder::disk_cache`].
+    #[must_use]
+    pub fn disk_cache(&self) -> Option<&DiskCache> {
+        self.inner.config.disk_cache.as_ref()
+    }
+
+    /// Same as the async `get`, going through the caches when enabled.
+    fn get(&self, path: &str) -> Result<BlobResponse, GeniusError> {
+        let config = &self.inner.config;
+        let key = CacheKey::path(path);
+        let body = match config.lookup(&key)? {
+            Lookup::Cached(body) => body,
+            Lookup::Request { request, stored } => {
+                let response = self.send(&request, path)?;
+                config.store(&key, stored, response)
+            }
+        };
+        client::parse_response(&body)
+    }
+
+    fn vote(&self, id: u32, action: &str) -> Result<Annotation, GeniusError> {
+        let request = annotation::vote_request(&self.inner.config, id, action)?;
+        let res = self.send_uncached(&request, &format!("/annotations/{id}/{action}"))?;
+        self.inner.config.invalidate_annotation(id);
+        res.annotation()
+    }
+
+    /// Same as the async `get_page`.
+    fn get_page(&self, url: &str) -> Result<String, GeniusError> {
+        let request = self.inner.config.page_request(url)?;
+        let response = self.send(&request, url)?;
+        Ok(String::from_utf8_lossy(&response.body).into_owned())
+    }
+
+    /// Same as the async `send_uncached`, the caches are not used.
+    fn send_uncached(
+        &self,
+        request: &HttpRequest,
+        path: &str,
+    ) -> Result<BlobResponse, GeniusError> {
+        let response = self.send(request, path)?;
+        client::parse_response(&response.body)
+    }
+
+    /// Send a request and read its body, waiting for the rate limit and retrying as the
+    /// [`crate::retry::RetryPolicy`] allows.
+    fn send(&self, request: &HttpRequest, path: &str) -> Result<HttpResponse, GeniusError> {
+        let config = &self.inner.config;
+        config.check_headers()?;
+        let mut attempt = 1;
+        loop {
+            if let Some(rate_limit) = &config.rate_limit {
+                std::thread::sleep(rate_limit.reserve());
+            }
+            let outcome = self.execute(request.clone());
+            let failure = match config.check(request, outcome, path) {
+                Ok(response) => return Ok(response),
+                Err(failure) => failure,
+            };
+            match config.retry_delay(attempt, &failure) {
+                Some(delay) => std::thread::sleep(delay),
+                None => return Err(failure.error),
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Single attempt of `request` with the blocking client.
+    fn execute(&self, request: HttpRequest) -> Result<HttpResponse, TransportError> {
+        let mut builder = self
+            .inner
+            .client
+            .request(request.method, request.url)
+            .headers(request.headers);
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
+        }
+        let response = builder.send().map_err(|e| transport::transport_error(&e))?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response
+            .bytes()
+            .map_err(|e| transport::transport_error(&e))?;
+        Ok(HttpResponse {
+            status,
+            headers,
+            body: body.to_vec(),
+        })
+    }
+}
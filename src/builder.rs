@@ -0,0 +1,243 @@
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT};
+use reqwest::Client;
+use std::time::Duration;
+
+use crate::auth::Scope;
+use crate::cache::ResponseCache;
+use crate::client::Config;
+use crate::disk_cache::DiskCache;
+use crate::links::GENIUS_URL;
+use crate::lyrics::LyricsStrategy;
+use crate::rate_limit::{RateLimit, TokenBucket};
+use crate::retry::RetryPolicy;
+use crate::transport::{ReqwestTransport, Transport};
+use crate::{Genius, Inner};
+use std::sync::Arc;
+
+/// Default base URL of the Genius API.
+pub const API_URL: &str = "https://api.genius.com";
+
+/// Builder for a configured [`Genius`] client, created with [`Genius::builder`].
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::Genius;
+/// use std::time::Duration;
+///
+/// let genius = Genius::builder()
+///     .token("my_token")
+///     .user_agent("my-app/1.0")
+///     .timeout(Duration::from_secs(10))
+///     .build();
+/// ```
+#[derive(Default)]
+pub struct GeniusBuilder {
+    token: Option<String>,
+    api_url: Option<String>,
+    web_url: Option<String>,
+    timeout: Option<Duration>,
+    user_agent: Option<String>,
+    headers: HeaderMap,
+    transport: Option<Box<dyn Transport>>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limit: Option<RateLimit>,
+    cache: Option<ResponseCache>,
+    disk_cache: Option<DiskCache>,
+    scopes: Option<Vec<Scope>>,
+    lyrics_strategy: LyricsStrategy,
+    #[cfg(feature = "blocking")]
+    blocking_client: Option<reqwest::blocking::Client>,
+}
+
+impl GeniusBuilder {
+    /// Same as [`Genius::builder`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Token sent as bearer authorization on every API request, if it isn't a valid header value the requests fail
+    /// with [`crate::error::GeniusError::InvalidHeader`].
+    #[must_use]
+    pub fn token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Base URL of the Genius API, defaults to [`API_URL`].
+    #[must_use]
+    pub fn api_url(mut self, url: impl Into<String>) -> Self {
+        self.api_url = Some(url.into());
+        self
+    }
+
+    /// Base URL of the genius.com pages read for the lyrics and the song urls, defaults to [`GENIUS_URL`]. The paths
+    /// of the song urls returned by the API are requested on it, so the client can be pointed at a local server.
+    #[must_use]
+    pub fn web_url(mut self, url: impl Into<String>) -> Self {
+        self.web_url = Some(url.into());
+        self
+    }
+
+    /// Timeout applied to every request.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Value of the `User-Agent` header sent with every request, if it isn't a valid header value the requests fail
+    /// with [`crate::error::GeniusError::InvalidHeader`].
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Add a header sent with every request.
+    #[must_use]
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Headers sent with every request, replacing the ones added before.
+    #[must_use]
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Use an already configured [`reqwest::Client`] instead of creating a new one.
+    #[must_use]
+    pub fn client(self, client: Client) -> Self {
+        self.transport(ReqwestTransport::new(client))
+    }
+
+    /// Send the requests with `transport` instead of [`ReqwestTransport`].
+    #[must_use]
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Box::new(transport));
+        self
+    }
+
+    /// Retry failed requests following `policy`, by default requests are not retried.
+    #[must_use]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Limit the rate of requests of the client and all its clones, by default there is no limit.
+    #[must_use]
+    pub const fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = Some(limit);
+        self
+    }
+
+    /// Cache the responses of the API `GET` requests like `search` or `get_song` in memory, by default nothing is cached.
+    #[must_use]
+    pub fn cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Store the API responses on disk and revalidate them with conditional requests, by default nothing is stored.
+    #[must_use]
+    pub fn disk_cache(mut self, disk_cache: DiskCache) -> Self {
+        self.disk_cache = Some(disk_cache);
+        self
+    }
+
+    /// Scopes given to the token, requests needing another scope fail with [`crate::error::GeniusError::MissingScope`]
+    /// without being sent. By default the scopes are unknown and the requests are always sent.
+    #[must_use]
+    pub fn scopes(mut self, scopes: impl IntoIterator<Item = Scope>) -> Self {
+        self.scopes = Some(scopes.into_iter().collect());
+        self
+    }
+
+    /// Where [`Genius::get_lyrics`] looks for the lyrics, defaults to [`LyricsStrategy::PreferApi`].
+    #[must_use]
+    pub const fn lyrics_strategy(mut self, strategy: LyricsStrategy) -> Self {
+        self.lyrics_strategy = strategy;
+        self
+    }
+
+    /// Use an already configured [`reqwest::blocking::Client`] for the client created by [`Self::build_blocking`].
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn blocking_client(mut self, client: reqwest::blocking::Client) -> Self {
+        self.blocking_client = Some(client);
+        self
+    }
+
+    /// Create the [`Genius`] client.
+    #[must_use]
+    pub fn build(mut self) -> Genius {
+        let transport = self
+            .transport
+            .take()
+            .unwrap_or_else(|| Box::new(ReqwestTransport::default()));
+        let inner = Inner {
+            transport,
+            config: self.into_config(),
+        };
+        Genius {
+            inner: Arc::new(inner),
+        }
+    }
+
+    /// Create a [`crate::blocking::Genius`] client, the [`Self::transport`] and [`Self::client`] are not used by it.
+    #[cfg(feature = "blocking")]
+    #[must_use]
+    pub fn build_blocking(mut self) -> crate::blocking::Genius {
+        let client = self.blocking_client.take().unwrap_or_default();
+        crate::blocking::Genius::new_with(client, self.into_config())
+    }
+
+    fn into_config(mut self) -> Config {
+        let mut invalid_header = None;
+        if let Some(user_agent) = self.user_agent {
+            match HeaderValue::from_str(&user_agent) {
+                Ok(user_agent) => {
+                    self.headers.insert(USER_AGENT, user_agent);
+                }
+                Err(_) => invalid_header = Some("user agent"),
+            }
+        }
+        let token = self.token.filter(|token| !token.is_empty());
+        let authorization = match token
+            .as_ref()
+            .map(|token| HeaderValue::from_str(&format!("Bearer {token}")))
+        {
+            Some(Ok(authorization)) => Some(authorization),
+            Some(Err(_)) => {
+                invalid_header = Some("token");
+                None
+            }
+            None => None,
+        };
+        let api_url = trim_url(self.api_url.as_deref().unwrap_or(API_URL));
+        Config {
+            authorization,
+            disk_cache: self
+                .disk_cache
+                .map(|disk_cache| disk_cache.namespace(&api_url, token.as_deref())),
+            api_url,
+            web_url: trim_url(self.web_url.as_deref().unwrap_or(GENIUS_URL)),
+            timeout: self.timeout,
+            headers: self.headers,
+            retry_policy: self.retry_policy.unwrap_or_else(RetryPolicy::none),
+            rate_limit: self.rate_limit.as_ref().map(TokenBucket::new),
+            cache: self.cache,
+            scopes: self.scopes,
+            lyrics_strategy: self.lyrics_strategy,
+            invalid_header,
+        }
+    }
+}
+
+fn trim_url(url: &str) -> String {
+    url.trim_end_matches('/').to_owned()
+}
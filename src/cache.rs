@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::client;
+use crate::web_page::WebPageLookup;
+use crate::TextFormat;
+
+#[cfg(test)]
+mod test {
+    use crate::cache::{CacheKey, ResponseCache};
+    use crate::TextFormat;
+    use std::time::Duration;
+
+    #[test]
+    fn get_insert_test() {
+        let cache = ResponseCache::new(10, Duration::from_secs(30));
+        let key = CacheKey::song(378_195, TextFormat::PLAIN);
+        assert!(cache.get(&key).is_none());
+        cache.insert(&key, b"{}".to_vec());
+        assert_eq!(&*cache.get(&key).unwrap(), b"{}");
+        assert!(cache
+            .get(&CacheKey::song(378_195, TextFormat::HTML))
+            .is_none());
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.len), (1, 2, 1));
+        cache.invalidate(&key);
+        assert!(cache.get(&key).is_none());
+    }
+
+    #[test]
+    fn ttl_test() {
+        let cache = ResponseCache::new(10, Duration::ZERO);
+        let key = CacheKey::album(27_501, TextFormat::PLAIN);
+        cache.insert(&key, b"{}".to_vec());
+        assert!(cache.get(&key).is_none());
+        assert_eq!(cache.stats().len, 0);
+    }
+
+    #[test]
+    fn lru_test() {
+        let cache = ResponseCache::new(2, Duration::from_secs(30));
+        let (a, b, c) = (
+            CacheKey::search("a"),
+            CacheKey::search("b"),
+            CacheKey::search("c"),
+        );
+        cache.insert(&a, b"a".to_vec());
+        cache.insert(&b, b"b".to_vec());
+        assert!(cache.get(&a).is_some());
+        cache.insert(&c, b"c".to_vec());
+        assert!(cache.get(&a).is_some());
+        assert!(cache.get(&b).is_none());
+        assert!(cache.get(&c).is_some());
+    }
+}
+
+/// Key of a cached response, the endpoint with its id and text format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey(String);
+
+impl CacheKey {
+    /// Key of [`crate::Genius::get_song`].
+    #[must_use]
+    pub fn song(id: u32, text_format: TextFormat) -> Self {
+        Self(format!("/songs/{id}?text_format={text_format}"))
+    }
+
+    /// Key of [`crate::Genius::get_album`].
+    #[must_use]
+    pub fn album(id: u32, text_format: TextFormat) -> Self {
+        Self(format!("/albums/{id}?text_format={text_format}"))
+    }
+
+    /// Key of [`crate::Genius::get_artist`].
+    #[must_use]
+    pub fn artist(id: u32, text_format: TextFormat) -> Self {
+        Self(format!("/artists/{id}?text_format={text_format}"))
+    }
+
+    /// Key of [`crate::Genius::get_annotation`].
+    #[must_use]
+    pub fn annotation(id: u32, text_format: TextFormat) -> Self {
+        Self(format!("/annotations/{id}?text_format={text_format}"))
+    }
+
+    /// Keys of [`crate::Genius::get_annotation`] for the annotation `id` in every text format.
+    pub(crate) fn annotations(id: u32) -> impl Iterator<Item = Self> {
+        TextFormat::combinations().map(move |text_format| Self::annotation(id, text_format))
+    }
+
+    /// Key of [`crate::Genius::lookup_web_page`].
+    #[must_use]
+    pub fn web_page(lookup: &WebPageLookup) -> Self {
+        Self(format!("/web_pages/lookup?{}", lookup.query()))
+    }
+
+    /// Key of [`crate::Genius::search`].
+    #[must_use]
+    pub fn search(q: &str) -> Self {
+        Self(format!("/search?q={}", client::encode(q)))
+    }
+
+    /// Key of [`crate::Genius::search_multi`].
+    #[must_use]
+    pub fn search_multi(q: &str) -> Self {
+        Self(format!("/search/multi?q={}", client::encode(q)))
+    }
+
+    /// Key of any other API path.
+    #[must_use]
+    pub fn path(path: &str) -> Self {
+        Self(path.to_owned())
+    }
+
+    /// API path requested for this key.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Counters of a [`ResponseCache`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Lookups answered from the cache.
+    pub hits: u64,
+    /// Lookups that needed a request.
+    pub misses: u64,
+    /// Number of responses currently cached.
+    pub len: usize,
+}
+
+/// In-memory cache of API responses, enabled with [`crate::GeniusBuilder::cache`].
+///
+/// Holds at most `capacity` responses for `ttl` each, when full the least recently used response is dropped.
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::{cache::ResponseCache, Genius};
+/// use std::time::Duration;
+///
+/// let genius = Genius::builder()
+///     .token("my_token")
+///     .cache(ResponseCache::new(1000, Duration::from_secs(3600)))
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct ResponseCache {
+    capacity: usize,
+    ttl: Duration,
+    entries: Mutex<Entries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    map: HashMap<CacheKey, Entry>,
+    clock: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    body: Arc<[u8]>,
+    inserted_at: Instant,
+    used_at: u64,
+}
+
+impl ResponseCache {
+    /// Cache holding at most `capacity` responses for `ttl`.
+    #[must_use]
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            ttl,
+            entries: Mutex::default(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Cached body of `key` if it hasn't expired.
+    #[must_use]
+    pub fn get(&self, key: &CacheKey) -> Option<Arc<[u8]>> {
+        let mut entries = self.entries();
+        entries.clock += 1;
+        let clock = entries.clock;
+        let body = match entries.map.get_mut(key) {
+            Some(entry) if entry.inserted_at.elapsed() < self.ttl => {
+                entry.used_at = clock;
+                Some(Arc::clone(&entry.body))
+            }
+            Some(_) => {
+                entries.map.remove(key);
+                None
+            }
+            None => None,
+        };
+        drop(entries);
+        let counter = if body.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        body
+    }
+
+    /// Cache `body` for `key`, dropping the least recently used response if the cache is full.
+    pub fn insert(&self, key: &CacheKey, body: impl Into<Arc<[u8]>>) {
+        let mut entries = self.entries();
+        entries.clock += 1;
+        let clock = entries.clock;
+        if entries.map.len() >= self.capacity && !entries.map.contains_key(key) {
+            let ttl = self.ttl;
+            entries
+                .map
+                .retain(|_, entry| entry.inserted_at.elapsed() < ttl);
+            if entries.map.len() >= self.capacity {
+                let oldest = entries
+                    .map
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.used_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    entries.map.remove(&oldest);
+                }
+            }
+        }
+        entries.map.insert(
+            key.clone(),
+            Entry {
+                body: body.into(),
+                inserted_at: Instant::now(),
+                used_at: clock,
+            },
+        );
+    }
+
+    /// Drop the cached response of `key`.
+    pub fn invalidate(&self, key: &CacheKey) {
+        self.entries().map.remove(key);
+    }
+
+    /// Drop all cached responses.
+    pub fn clear(&self) {
+        self.entries().map.clear();
+    }
+
+    /// Hit and miss counters since the cache was created.
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            len: self.entries().map.len(),
+        }
+    }
+
+    fn entries(&self) -> MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,286 @@
+use reqwest::header::{
+    HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH,
+    LAST_MODIFIED, RETRY_AFTER,
+};
+use reqwest::{Method, StatusCode};
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::auth::Scope;
+use crate::cache::{CacheKey, ResponseCache};
+use crate::disk_cache::{DiskCache, StoredResponse};
+use crate::error::GeniusError;
+use crate::lyrics::LyricsStrategy;
+use crate::rate_limit::TokenBucket;
+use crate::retry::{self, RetryPolicy};
+use crate::transport::{HttpRequest, HttpResponse, TransportError, TransportErrorKind};
+use crate::{BlobResponse, Response};
+
+/// Configuration shared by the async and the blocking clients, the only difference between them is how the
+/// requests are sent and how they wait.
+pub struct Config {
+    pub authorization: Option<HeaderValue>,
+    pub api_url: String,
+    pub web_url: String,
+    pub timeout: Option<Duration>,
+    pub headers: HeaderMap,
+    pub retry_policy: RetryPolicy,
+    pub rate_limit: Option<TokenBucket>,
+    pub cache: Option<ResponseCache>,
+    pub disk_cache: Option<DiskCache>,
+    pub scopes: Option<Vec<Scope>>,
+    pub lyrics_strategy: LyricsStrategy,
+    /// `"token"` or `"user agent"` when the builder was given one that isn't a valid header value.
+    pub invalid_header: Option<&'static str>,
+}
+
+/// Outcome of looking up the caches before an API `GET`.
+#[allow(clippy::large_enum_variant)]
+pub enum Lookup {
+    /// The body is cached, no request is needed.
+    Cached(Arc<[u8]>),
+    /// `request` must be sent and its response given to [`Config::store`] with `stored`.
+    Request {
+        request: HttpRequest,
+        stored: Option<StoredResponse>,
+    },
+}
+
+/// Failed attempt of a request, with whether it can be retried and the `Retry-After` delay.
+pub struct Failure {
+    pub error: GeniusError,
+    retryable: bool,
+    retry_after: Option<Duration>,
+}
+
+impl Config {
+    /// Request to the Genius API, `path` is appended to the configured API URL and the token is sent as bearer.
+    pub fn api_request(&self, method: Method, path: &str) -> HttpRequest {
+        let mut request = self.request(method, &format!("{}{path}", self.api_url));
+        if let Some(authorization) = &self.authorization {
+            request.headers.insert(AUTHORIZATION, authorization.clone());
+        }
+        request
+    }
+
+    /// Request to the Genius API needing `scope`, with `body` sent as JSON.
+    pub fn scoped_request<T: Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        scope: Scope,
+        body: Option<&T>,
+    ) -> Result<HttpRequest, GeniusError> {
+        if self
+            .scopes
+            .as_ref()
+            .is_some_and(|scopes| !scopes.contains(&scope))
+        {
+            return Err(GeniusError::MissingScope(scope));
+        }
+        let mut request = self.api_request(method, path);
+        if let Some(body) = body {
+            set_json_body(&mut request, body)?;
+        }
+        Ok(request)
+    }
+
+    /// Request of a genius.com page, without the token. Pages are never cached, so it fails with
+    /// [`GeniusError::OfflineCacheMiss`] when the disk cache is offline.
+    pub fn page_request(&self, url: &str) -> Result<HttpRequest, GeniusError> {
+        if self.disk_cache.as_ref().is_some_and(DiskCache::is_offline) {
+            return Err(GeniusError::OfflineCacheMiss(url.to_owned()));
+        }
+        Ok(self.request(Method::GET, url))
+    }
+
+    /// Request with the configured headers, user agent and timeout.
+    pub fn request(&self, method: Method, url: &str) -> HttpRequest {
+        HttpRequest {
+            method,
+            url: url.to_owned(),
+            headers: self.headers.clone(),
+            body: None,
+            timeout: self.timeout,
+        }
+    }
+
+    /// Fail with [`GeniusError::InvalidHeader`] if the token or the user agent couldn't be made a header.
+    pub const fn check_headers(&self) -> Result<(), GeniusError> {
+        match self.invalid_header {
+            Some(header) => Err(GeniusError::InvalidHeader(header)),
+            None => Ok(()),
+        }
+    }
+
+    /// Look up the memory and disk caches for an API `GET` of `key`, the request is made conditional when the disk
+    /// cache has a stale response.
+    pub fn lookup(&self, key: &CacheKey) -> Result<Lookup, GeniusError> {
+        if let Some(body) = self.cache.as_ref().and_then(|cache| cache.get(key)) {
+            return Ok(Lookup::Cached(body));
+        }
+        let mut request = self.api_request(Method::GET, key.as_str());
+        let Some(disk_cache) = &self.disk_cache else {
+            return Ok(Lookup::Request {
+                request,
+                stored: None,
+            });
+        };
+        let stored = disk_cache.get(key);
+        match stored {
+            Some(stored) if disk_cache.is_offline() || disk_cache.is_fresh(&stored) => {
+                let body: Arc<[u8]> = stored.body.into();
+                if let Some(cache) = &self.cache {
+                    cache.insert(key, Arc::clone(&body));
+                }
+                return Ok(Lookup::Cached(body));
+            }
+            None if disk_cache.is_offline() => {
+                return Err(GeniusError::OfflineCacheMiss(key.as_str().to_owned()))
+            }
+            _ => {}
+        }
+        if let Some(stored) = &stored {
+            let conditions = [
+                (IF_NONE_MATCH, &stored.etag),
+                (IF_MODIFIED_SINCE, &stored.last_modified),
+            ];
+            for (name, value) in conditions {
+                if let Some(value) = value.as_deref().and_then(|v| HeaderValue::from_str(v).ok()) {
+                    request.headers.insert(name, value);
+                }
+            }
+        }
+        Ok(Lookup::Request { request, stored })
+    }
+
+    /// Drop the cached responses of [`crate::Genius::get_annotation`] for the annotation `id`, after it was changed.
+    pub fn invalidate_annotation(&self, id: u32) {
+        for key in CacheKey::annotations(id) {
+            if let Some(cache) = &self.cache {
+                cache.invalidate(&key);
+            }
+            if let Some(disk_cache) = &self.disk_cache {
+                disk_cache.invalidate(&key).ok();
+            }
+        }
+    }
+
+    /// Cache the response of a [`Lookup::Request`] and return its body, a `304` is answered with the stored body.
+    pub fn store(
+        &self,
+        key: &CacheKey,
+        stored: Option<StoredResponse>,
+        response: HttpResponse,
+    ) -> Arc<[u8]> {
+        let header = |name| {
+            response
+                .headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+        };
+        let body: Arc<[u8]> = match stored {
+            Some(stored) if response.status == StatusCode::NOT_MODIFIED => {
+                if let Some(disk_cache) = &self.disk_cache {
+                    disk_cache
+                        .insert(
+                            key,
+                            &stored.body,
+                            stored.etag.as_deref(),
+                            stored.last_modified.as_deref(),
+                        )
+                        .ok();
+                }
+                stored.body.into()
+            }
+            _ => {
+                if let Some(disk_cache) = &self.disk_cache {
+                    disk_cache
+                        .insert(key, &response.body, header(ETAG), header(LAST_MODIFIED))
+                        .ok();
+                }
+                response.body.into()
+            }
+        };
+        if let Some(cache) = &self.cache {
+            cache.insert(key, Arc::clone(&body));
+        }
+        body
+    }
+
+    /// Map the outcome of a single attempt of `request`, unsuccessful statuses are mapped with
+    /// [`GeniusError::from_status`].
+    ///
+    /// A `POST` is only retried when it was not processed, after a connection error or a `429`, so it is not
+    /// applied twice.
+    pub fn check(
+        &self,
+        request: &HttpRequest,
+        outcome: Result<HttpResponse, TransportError>,
+        path: &str,
+    ) -> Result<HttpResponse, Failure> {
+        let idempotent = request.method != Method::POST;
+        let response = outcome.map_err(|e| Failure {
+            retryable: self.retry_policy.retries_transport_errors()
+                && (e.kind == TransportErrorKind::Connect
+                    || idempotent && e.kind == TransportErrorKind::Timeout),
+            error: GeniusError::RequestError(e.to_string()),
+            retry_after: None,
+        })?;
+        let status = response.status;
+        if status.is_success() || status == StatusCode::NOT_MODIFIED {
+            return Ok(response);
+        }
+        let retry_after = response
+            .headers
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(retry::parse_retry_after);
+        let mut error = GeniusError::from_status(status.as_u16(), path, &response.body);
+        if let GeniusError::RateLimited {
+            retry_after: error_retry_after,
+            ..
+        } = &mut error
+        {
+            *error_retry_after = retry_after;
+        }
+        Err(Failure {
+            error,
+            retryable: self.retry_policy.retries_status(status.as_u16())
+                && (idempotent || status == StatusCode::TOO_MANY_REQUESTS),
+            retry_after,
+        })
+    }
+
+    /// Delay before retrying after the failed attempt number `attempt`, `None` if it must not be retried.
+    pub fn retry_delay(&self, attempt: u32, failure: &Failure) -> Option<Duration> {
+        if failure.retryable {
+            self.retry_policy.delay(attempt, failure.retry_after)
+        } else {
+            None
+        }
+    }
+}
+
+/// Set `body` as the JSON body of `request`.
+pub fn set_json_body<T: Serialize>(request: &mut HttpRequest, body: &T) -> Result<(), GeniusError> {
+    request
+        .headers
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    request.body =
+        Some(serde_json::to_vec(body).map_err(|e| GeniusError::ParseError(e.to_string()))?);
+    Ok(())
+}
+
+/// Parse the `response` field of an API body.
+pub fn parse_response(body: &[u8]) -> Result<BlobResponse, GeniusError> {
+    serde_json::from_slice::<Response>(body)
+        .map(|res| res.response)
+        .map_err(|e| GeniusError::ParseError(e.to_string()))
+}
+
+/// Encode `value` for a query parameter.
+pub fn encode(value: &str) -> String {
+    form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::cache::CacheKey;
+
+/// Counter making the temporary files of concurrent inserts distinct.
+static TEMP_ID: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(test)]
+mod test {
+    use crate::cache::CacheKey;
+    use crate::disk_cache::DiskCache;
+    use crate::TextFormat;
+    use std::time::Duration;
+
+    #[test]
+    fn insert_get_test() {
+        let dir = std::env::temp_dir().join(format!("genius-rs-disk-cache-{}", std::process::id()));
+        let cache = DiskCache::new(&dir);
+        let key = CacheKey::song(378_195, TextFormat::PLAIN);
+        assert!(cache.get(&key).is_none());
+        cache
+            .insert(
+                &key,
+                b"{}",
+                Some("\"abc\""),
+                Some("Wed, 21 Oct 2015 07:28:00 GMT"),
+            )
+            .unwrap();
+
+        let reopened = DiskCache::new(&dir).max_age(Duration::from_secs(30));
+        let stored = reopened.get(&key).unwrap();
+        assert_eq!(stored.body, b"{}");
+        assert_eq!(stored.etag.as_deref(), Some("\"abc\""));
+        assert!(reopened.is_fresh(&stored));
+        assert!(!DiskCache::new(&dir).is_fresh(&stored));
+
+        assert!(reopened
+            .clone()
+            .namespace("https://api.genius.com", Some("token"))
+            .get(&key)
+            .is_none());
+
+        reopened.invalidate(&key).unwrap();
+        assert!(reopened.get(&key).is_none());
+        reopened.clear().unwrap();
+        assert!(!dir.exists());
+    }
+}
+
+/// On-disk cache of raw API responses that survives restarts, enabled with [`crate::GeniusBuilder::disk_cache`].
+///
+/// Responses are stored under a directory with their `ETag` and `Last-Modified` headers, a stored response older
+/// than `max_age` is revalidated with `If-None-Match` and `If-Modified-Since` so unchanged documents are not
+/// downloaded again. The responses are keyed by the API URL and the token of the client, so clients sharing the
+/// directory don't read each other's responses, use [`crate::Genius::disk_cache`] to invalidate them. In offline mode no request is sent and a missing response is a
+/// [`crate::error::GeniusError::OfflineCacheMiss`].
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::{disk_cache::DiskCache, Genius};
+///
+/// let genius = Genius::builder()
+///     .token("my_token")
+///     .disk_cache(DiskCache::new("/tmp/genius-cache"))
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+    max_age: Duration,
+    offline: bool,
+    namespace: String,
+}
+
+/// Response read from a [`DiskCache`].
+#[derive(Debug)]
+pub(crate) struct StoredResponse {
+    pub(crate) body: Vec<u8>,
+    pub(crate) etag: Option<String>,
+    pub(crate) last_modified: Option<String>,
+    stored_at: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Metadata {
+    path: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    stored_at: u64,
+}
+
+impl DiskCache {
+    /// Cache storing its responses under `dir`, which is created when the first response is stored.
+    #[must_use]
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_owned(),
+            max_age: Duration::ZERO,
+            offline: false,
+            namespace: String::new(),
+        }
+    }
+
+    /// Age under which a stored response is used without revalidation, by default every use is revalidated.
+    #[must_use]
+    pub const fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Only serve stored responses and never send a request.
+    #[must_use]
+    pub const fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// If this cache is in offline mode.
+    #[must_use]
+    pub const fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Remove the stored response of `key`.
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be removed.
+    pub fn invalidate(&self, key: &CacheKey) -> io::Result<()> {
+        match fs::remove_file(self.file(key)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    /// Remove the cache directory with all the stored responses.
+    ///
+    /// # Errors
+    ///
+    /// If the directory can't be removed.
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    /// Cache keyed by `api_url` and a fingerprint of `token`, set by the builder for its client.
+    pub(crate) fn namespace(mut self, api_url: &str, token: Option<&str>) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(api_url.as_bytes());
+        if let Some(token) = token {
+            hasher.update([0]);
+            hasher.update(token.as_bytes());
+        }
+        self.namespace = format!("{:x}", hasher.finalize());
+        self
+    }
+
+    pub(crate) fn get(&self, key: &CacheKey) -> Option<StoredResponse> {
+        let file = fs::read(self.file(key)).ok()?;
+        let split = file.iter().position(|&byte| byte == b'\n')?;
+        let metadata = serde_json::from_slice::<Metadata>(&file[..split]).ok()?;
+        if metadata.path != key.as_str() {
+            return None;
+        }
+        Some(StoredResponse {
+            body: file[split + 1..].to_vec(),
+            etag: metadata.etag,
+            last_modified: metadata.last_modified,
+            stored_at: metadata.stored_at,
+        })
+    }
+
+    pub(crate) fn insert(
+        &self,
+        key: &CacheKey,
+        body: &[u8],
+        etag: Option<&str>,
+        last_modified: Option<&str>,
+    ) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let metadata = Metadata {
+            path: key.as_str().to_owned(),
+            etag: etag.map(str::to_owned),
+            last_modified: last_modified.map(str::to_owned),
+            stored_at: now(),
+        };
+        // The metadata line and the body are written to a temporary file renamed over the stored one, so a reader
+        // never sees a partial write or a body with the headers of another one.
+        let mut file = serde_json::to_vec(&metadata)?;
+        file.push(b'\n');
+        file.extend_from_slice(body);
+        let temp = self.dir.join(format!(
+            "{}.{}-{}.tmp",
+            self.file_name(key),
+            std::process::id(),
+            TEMP_ID.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&temp, file)?;
+        fs::rename(&temp, self.file(key)).inspect_err(|_| {
+            fs::remove_file(&temp).ok();
+        })
+    }
+
+    /// If `stored` is recent enough to be used without revalidation.
+    pub(crate) fn is_fresh(&self, stored: &StoredResponse) -> bool {
+        now().saturating_sub(stored.stored_at) < self.max_age.as_secs()
+    }
+
+    fn file(&self, key: &CacheKey) -> PathBuf {
+        self.dir.join(format!("{}.cache", self.file_name(key)))
+    }
+
+    fn file_name(&self, key: &CacheKey) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.namespace.as_bytes());
+        hasher.update([0]);
+        hasher.update(key.as_str().as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| now.as_secs())
+}
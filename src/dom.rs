@@ -0,0 +1,586 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+#[cfg(test)]
+mod test {
+    use crate::dom::{DomElement, DomNode, Image, Link, Visitor};
+
+    fn description() -> DomNode {
+        serde_json::from_str(
+            r#"{"tag":"root","children":[
+                {"tag":"p","children":["Chandelier is the ",{"tag":"em","children":["lead"]}," single of ",
+                    {"tag":"a","attributes":{"href":"https://genius.com/albums/Sia/1000-forms-of-fear"},"children":["1000 Forms of Fear"]},"."]},
+                {"tag":"blockquote","children":[{"tag":"p","children":["Party girls don't get hurt",{"tag":"br"},"Can't feel anything"]}]},
+                {"tag":"ul","children":[{"tag":"li","children":["Pop"]},{"tag":"li","children":[{"tag":"strong","children":["Electropop"]}]}]},
+                {"tag":"img","attributes":{"src":"https://images.genius.com/sia.jpg","alt":"Sia","onerror":"alert(1)"}},
+                {"tag":"script","children":["alert(1)"]},
+                {"tag":"a","attributes":{"href":"javascript:alert(1)"},"children":["<click>"]}
+            ]}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn serde_test() {
+        let dom = description();
+        let json = serde_json::to_string(&dom).unwrap();
+        assert_eq!(serde_json::from_str::<DomNode>(&json).unwrap(), dom);
+    }
+
+    #[test]
+    fn plain_test() {
+        assert_eq!(
+            description().to_plain(),
+            "Chandelier is the lead single of 1000 Forms of Fear.\n\nParty girls don't get hurt\nCan't feel anything\n\nPop\nElectropop\n\n<click>"
+        );
+    }
+
+    #[test]
+    fn markdown_test() {
+        assert_eq!(
+            description().to_markdown(),
+            "Chandelier is the *lead* single of [1000 Forms of Fear](https://genius.com/albums/Sia/1000-forms-of-fear).\n\n> Party girls don't get hurt  \n> Can't feel anything\n\n- Pop\n- **Electropop**\n\n![Sia](https://images.genius.com/sia.jpg)\\<click\\>"
+        );
+    }
+
+    #[test]
+    fn markdown_url_test() {
+        let dom: DomNode = serde_json::from_str(
+            r#"{"tag":"root","children":[
+                {"tag":"a","attributes":{"href":"https://x/a) [c](javascript:alert(1)"},"children":["b"]},
+                {"tag":"img","attributes":{"src":"https://x/a b.jpg\\","alt":"d"}}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            dom.to_markdown(),
+            "[b](https://x/a%29%20[c]%28javascript:alert%281%29)![d](https://x/a%20b.jpg%5C)"
+        );
+    }
+
+    #[test]
+    fn sanitized_html_test() {
+        assert_eq!(
+            description().to_sanitized_html(),
+            "<p>Chandelier is the <em>lead</em> single of <a href=\"https://genius.com/albums/Sia/1000-forms-of-fear\">1000 Forms of Fear</a>.</p><blockquote><p>Party girls don&#39;t get hurt<br>Can&#39;t feel anything</p></blockquote><ul><li>Pop</li><li><strong>Electropop</strong></li></ul><img src=\"https://images.genius.com/sia.jpg\" alt=\"Sia\"><a>&lt;click&gt;</a>"
+        );
+    }
+
+    #[test]
+    fn links_images_test() {
+        let dom = description();
+        assert_eq!(
+            dom.links(),
+            [
+                Link {
+                    href: "https://genius.com/albums/Sia/1000-forms-of-fear".to_owned(),
+                    text: "1000 Forms of Fear".to_owned(),
+                },
+                Link {
+                    href: "javascript:alert(1)".to_owned(),
+                    text: "<click>".to_owned(),
+                },
+            ]
+        );
+        assert_eq!(
+            dom.images(),
+            [Image {
+                src: "https://images.genius.com/sia.jpg".to_owned(),
+                alt: Some("Sia".to_owned()),
+            }]
+        );
+    }
+
+    #[test]
+    fn visitor_test() {
+        #[derive(Default)]
+        struct Tags(Vec<String>);
+
+        impl Visitor for Tags {
+            fn enter_element(&mut self, element: &DomElement) -> bool {
+                self.0.push(element.tag.clone());
+                element.tag != "blockquote"
+            }
+        }
+
+        let mut tags = Tags::default();
+        description().walk(&mut tags);
+        assert_eq!(
+            tags.0,
+            [
+                "root",
+                "p",
+                "em",
+                "a",
+                "blockquote",
+                "ul",
+                "li",
+                "li",
+                "strong",
+                "img",
+                "script",
+                "a"
+            ]
+        );
+    }
+}
+
+/// Node of a body in the [`crate::TextFormat::DOM`] format, either text or an element with children.
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::dom::DomNode;
+///
+/// let dom: DomNode = serde_json::from_str(
+///     r#"{"tag":"root","children":[{"tag":"p","children":["Hello ",{"tag":"em","children":["world"]}]}]}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(dom.to_plain(), "Hello world");
+/// assert_eq!(dom.to_markdown(), "Hello *world*");
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum DomNode {
+    Text(String),
+    Element(DomElement),
+}
+
+/// Element of a [`DomNode`] tree, the root of a body is an element with the tag `root`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DomElement {
+    /// Name of the HTML tag like `p`, `a` or `img`.
+    pub tag: String,
+    /// HTML attributes like `href` or `src`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub attributes: BTreeMap<String, Value>,
+    /// Genius data like the `api_path` and `id` of an annotated fragment.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub data: BTreeMap<String, Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<DomNode>,
+}
+
+/// Link found in a [`DomNode`] tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    /// Target of the link as found in the tree, it is not checked.
+    pub href: String,
+    /// Plain text of the link.
+    pub text: String,
+}
+
+/// Embedded image found in a [`DomNode`] tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub src: String,
+    pub alt: Option<String>,
+}
+
+/// Callbacks of [`DomNode::walk`], called in document order.
+pub trait Visitor {
+    /// Called for each text node.
+    fn visit_text(&mut self, _text: &str) {}
+
+    /// Called before the children of `element`, they are skipped if it returns `false`.
+    fn enter_element(&mut self, _element: &DomElement) -> bool {
+        true
+    }
+
+    /// Called after the children of `element`, even if they were skipped.
+    fn leave_element(&mut self, _element: &DomElement) {}
+}
+
+/// Tags rendered on their own lines.
+const BLOCK_TAGS: [&str; 14] = [
+    "p",
+    "div",
+    "blockquote",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "ul",
+    "ol",
+    "pre",
+    "hr",
+    "table",
+];
+
+/// Tags kept by [`DomNode::to_sanitized_html`], the others are replaced by their children.
+const ALLOWED_TAGS: [&str; 25] = [
+    "a",
+    "b",
+    "blockquote",
+    "br",
+    "code",
+    "em",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "i",
+    "img",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "s",
+    "small",
+    "strong",
+    "sub",
+    "sup",
+    "ul",
+];
+
+/// Tags dropped with their children by [`DomNode::to_sanitized_html`].
+const DROPPED_TAGS: [&str; 7] = [
+    "script", "style", "iframe", "object", "embed", "noscript", "template",
+];
+
+impl DomNode {
+    /// Walk the tree depth first, calling `visitor` on each node.
+    pub fn walk<V: Visitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            Self::Text(text) => visitor.visit_text(text),
+            Self::Element(element) => {
+                if visitor.enter_element(element) {
+                    for child in &element.children {
+                        child.walk(visitor);
+                    }
+                }
+                visitor.leave_element(element);
+            }
+        }
+    }
+
+    /// Text of the tree without scripts, paragraphs are separated by a blank line and line breaks are kept.
+    #[must_use]
+    pub fn to_plain(&self) -> String {
+        let mut renderer = PlainRenderer::default();
+        self.walk(&mut renderer);
+        tidy(&renderer.0)
+    }
+
+    /// Markdown of the tree, unknown tags are replaced by their children and unsafe links by their text as in
+    /// [`Self::to_sanitized_html`].
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        markdown(self, &mut out);
+        tidy(&out)
+    }
+
+    /// HTML of the tree keeping only formatting tags, links and images, safe to embed in a page.
+    ///
+    /// Scripts and other active contents are dropped and only `http`, `https`, `mailto` and relative URLs are kept.
+    #[must_use]
+    pub fn to_sanitized_html(&self) -> String {
+        let mut out = String::new();
+        sanitized_html(self, &mut out);
+        out
+    }
+
+    /// Links of the tree in document order.
+    #[must_use]
+    pub fn links(&self) -> Vec<Link> {
+        #[derive(Default)]
+        struct Links(Vec<Link>);
+
+        impl Visitor for Links {
+            fn enter_element(&mut self, element: &DomElement) -> bool {
+                if let Some(href) = element.attribute("href").filter(|_| element.tag == "a") {
+                    self.0.push(Link {
+                        href: href.to_owned(),
+                        text: DomNode::Element(element.clone()).to_plain(),
+                    });
+                }
+                true
+            }
+        }
+
+        let mut links = Links::default();
+        self.walk(&mut links);
+        links.0
+    }
+
+    /// Embedded images of the tree in document order.
+    #[must_use]
+    pub fn images(&self) -> Vec<Image> {
+        #[derive(Default)]
+        struct Images(Vec<Image>);
+
+        impl Visitor for Images {
+            fn enter_element(&mut self, element: &DomElement) -> bool {
+                if let Some(src) = element.attribute("src").filter(|_| element.tag == "img") {
+                    self.0.push(Image {
+                        src: src.to_owned(),
+                        alt: element.attribute("alt").map(str::to_owned),
+                    });
+                }
+                true
+            }
+        }
+
+        let mut images = Images::default();
+        self.walk(&mut images);
+        images.0
+    }
+}
+
+impl DomElement {
+    /// Value of the attribute `name` if it is a string.
+    #[must_use]
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).and_then(Value::as_str)
+    }
+
+    fn is_block(&self) -> bool {
+        BLOCK_TAGS.contains(&self.tag.as_str())
+    }
+}
+
+#[derive(Default)]
+struct PlainRenderer(String);
+
+impl Visitor for PlainRenderer {
+    fn visit_text(&mut self, text: &str) {
+        self.0.push_str(text);
+    }
+
+    fn enter_element(&mut self, element: &DomElement) -> bool {
+        match element.tag.as_str() {
+            "br" | "li" => self.0.push('\n'),
+            tag if DROPPED_TAGS.contains(&tag) => return false,
+            _ if element.is_block() => self.0.push_str("\n\n"),
+            _ => {}
+        }
+        true
+    }
+
+    fn leave_element(&mut self, element: &DomElement) {
+        if element.is_block() {
+            self.0.push_str("\n\n");
+        }
+    }
+}
+
+fn markdown(node: &DomNode, out: &mut String) {
+    let element = match node {
+        DomNode::Text(text) => return escape_markdown(text, out),
+        DomNode::Element(element) => element,
+    };
+    let children = |out: &mut String| {
+        for child in &element.children {
+            markdown(child, out);
+        }
+    };
+    match element.tag.as_str() {
+        "p" | "div" | "table" => {
+            out.push_str("\n\n");
+            children(out);
+            out.push_str("\n\n");
+        }
+        "br" => out.push_str("  \n"),
+        "hr" => out.push_str("\n\n---\n\n"),
+        "em" | "i" => wrap(out, "*", children),
+        "strong" | "b" => wrap(out, "**", children),
+        "s" => wrap(out, "~~", children),
+        "code" => {
+            out.push('`');
+            out.push_str(&node.to_plain());
+            out.push('`');
+        }
+        "pre" => {
+            out.push_str("\n\n```\n");
+            out.push_str(&node.to_plain());
+            out.push_str("\n```\n\n");
+        }
+        "a" => match element.attribute("href").filter(|href| is_safe_url(href)) {
+            Some(href) => {
+                out.push('[');
+                children(out);
+                out.push_str("](");
+                escape_url(href, out);
+                out.push(')');
+            }
+            None => children(out),
+        },
+        "img" => {
+            if let Some(src) = element.attribute("src").filter(|src| is_safe_url(src)) {
+                let alt = element.attribute("alt").unwrap_or_default();
+                out.push_str("![");
+                escape_markdown(alt, out);
+                out.push_str("](");
+                escape_url(src, out);
+                out.push(')');
+            }
+        }
+        "blockquote" => {
+            let mut quote = String::new();
+            children(&mut quote);
+            out.push_str("\n\n");
+            for line in tidy(&quote).lines() {
+                out.push_str(if line.is_empty() { ">" } else { "> " });
+                out.push_str(line);
+                out.push('\n');
+            }
+            out.push('\n');
+        }
+        "ul" | "ol" => {
+            out.push_str("\n\n");
+            let items = element.children.iter().filter_map(|child| match child {
+                DomNode::Element(item) if item.tag == "li" => Some(item),
+                _ => None,
+            });
+            for (index, item) in items.enumerate() {
+                let marker = if element.tag == "ol" {
+                    format!("{}. ", index + 1)
+                } else {
+                    "- ".to_owned()
+                };
+                let mut content = String::new();
+                for child in &item.children {
+                    markdown(child, &mut content);
+                }
+                for (number, line) in tidy(&content).lines().enumerate() {
+                    if number == 0 {
+                        out.push_str(&marker);
+                    } else if !line.is_empty() {
+                        out.push_str(&" ".repeat(marker.len()));
+                    }
+                    out.push_str(line);
+                    out.push('\n');
+                }
+            }
+            out.push('\n');
+        }
+        tag if tag.len() == 2 && tag.starts_with('h') && element.is_block() => {
+            out.push_str("\n\n");
+            out.push_str(&"#".repeat(usize::from(tag.as_bytes()[1] - b'0')));
+            out.push(' ');
+            children(out);
+            out.push_str("\n\n");
+        }
+        tag if DROPPED_TAGS.contains(&tag) => {}
+        _ => children(out),
+    }
+}
+
+fn wrap(out: &mut String, marker: &str, children: impl Fn(&mut String)) {
+    out.push_str(marker);
+    children(out);
+    out.push_str(marker);
+}
+
+fn escape_markdown(text: &str, out: &mut String) {
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '[' | ']' | '`' | '<' | '>') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+}
+
+/// Percent-encode the characters that could end a Markdown link destination early.
+fn escape_url(url: &str, out: &mut String) {
+    for c in url.trim().chars() {
+        if matches!(c, '(' | ')' | '<' | '>' | '\\') || c.is_whitespace() {
+            let mut buf = [0; 4];
+            for byte in c.encode_utf8(&mut buf).bytes() {
+                let _ = write!(out, "%{byte:02X}");
+            }
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+fn sanitized_html(node: &DomNode, out: &mut String) {
+    let element = match node {
+        DomNode::Text(text) => return escape_html(text, out),
+        DomNode::Element(element) => element,
+    };
+    let tag = element.tag.as_str();
+    if DROPPED_TAGS.contains(&tag) {
+        return;
+    }
+    let allowed = ALLOWED_TAGS.contains(&tag);
+    if allowed {
+        out.push('<');
+        out.push_str(tag);
+        let attributes: &[&str] = match tag {
+            "a" => &["href", "title"],
+            "img" => &["src", "alt", "width", "height"],
+            _ => &[],
+        };
+        for name in attributes {
+            let value = match element.attribute(name) {
+                Some(value) if !matches!(*name, "href" | "src") || is_safe_url(value) => value,
+                _ => continue,
+            };
+            out.push(' ');
+            out.push_str(name);
+            out.push_str("=\"");
+            escape_html(value, out);
+            out.push('"');
+        }
+        out.push('>');
+        if matches!(tag, "br" | "hr" | "img") {
+            return;
+        }
+    }
+    for child in &element.children {
+        sanitized_html(child, out);
+    }
+    if allowed {
+        out.push_str("</");
+        out.push_str(tag);
+        out.push('>');
+    }
+}
+
+fn escape_html(text: &str, out: &mut String) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+/// If `url` is relative or uses the `http`, `https` or `mailto` scheme.
+fn is_safe_url(url: &str) -> bool {
+    let url = url.trim().to_lowercase();
+    match url.find([':', '/', '?', '#']) {
+        Some(index) if url[index..].starts_with(':') => {
+            matches!(&url[..index], "http" | "https" | "mailto")
+        }
+        _ => true,
+    }
+}
+
+/// Remove the spaces of blank lines, collapse consecutive blank lines and trim the blank lines around the text.
+fn tidy(text: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    for line in text.lines() {
+        let line = if line.trim().is_empty() { "" } else { line };
+        if !(line.is_empty() && lines.last().is_none_or(|last| last.is_empty())) {
+            lines.push(line);
+        }
+    }
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+    lines.join("\n")
+}
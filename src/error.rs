@@ -1,15 +1,234 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::fmt;
+use std::time::Duration;
 use thiserror::Error;
 
+use crate::auth::Scope;
+
+#[cfg(test)]
+mod test {
+    use crate::error::GeniusError;
+
+    #[test]
+    fn from_status_test() {
+        let body = br#"{"meta":{"status":404,"message":"Not found"}}"#;
+        let error = GeniusError::from_status(404, "/songs/0", body);
+        assert!(matches!(error, GeniusError::NotFound(_)));
+        let api_error = error.api_error().unwrap();
+        assert_eq!(api_error.status, 404);
+        assert_eq!(api_error.path, "/songs/0");
+        assert_eq!(api_error.message.as_deref(), Some("Not found"));
+
+        let body = br#"{"error":"invalid_token","error_description":"The access token provided is invalid"}"#;
+        let error = GeniusError::from_status(401, "/search?q=Sia", body);
+        assert!(matches!(error, GeniusError::Unauthorized(_)));
+        assert_eq!(
+            error.api_error().unwrap().message.as_deref(),
+            Some("The access token provided is invalid")
+        );
+
+        assert!(matches!(
+            GeniusError::from_status(502, "/albums/1", b"<html></html>"),
+            GeniusError::InternalServerError(_)
+        ));
+        let body = br#"{"meta":{"status":422,"message":"Validation failed"},"response":{"errors":{"fragment":["can't be blank"]}}}"#;
+        let error = GeniusError::from_status(422, "/annotations", body);
+        assert!(
+            matches!(&error, GeniusError::Validation { errors, .. } if errors == &["fragment can't be blank"])
+        );
+
+        assert!(matches!(
+            GeniusError::from_status(429, "/songs/1", b""),
+            GeniusError::RateLimited {
+                retry_after: None,
+                ..
+            }
+        ));
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum GeniusError {
-    #[error("{0}")]
-    InternalServerError(String),
+    /// Status `400`.
+    #[error("Bad request: {0}")]
+    BadRequest(ApiError),
+    /// Status `401`, the token is missing or invalid.
+    #[error("Unauthorized: {0}")]
+    Unauthorized(ApiError),
+    /// Status `403`, the token doesn't have the permission for this request.
+    #[error("Forbidden: {0}")]
+    Forbidden(ApiError),
+    /// Status `404`, the resource doesn't exist.
+    #[error("Not found: {0}")]
+    NotFound(ApiError),
+    /// Status `422`, the content sent is not valid, `errors` are the messages of each invalid field.
+    #[error("Validation failed: {error}")]
+    Validation {
+        errors: Vec<String>,
+        error: ApiError,
+    },
+    /// Status `429`, too many requests were made with this token and the retries are exhausted.
+    /// `retry_after` is the delay asked by the `Retry-After` header.
+    #[error("Rate limited: {error}")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        error: ApiError,
+    },
+    /// Status `5xx`.
+    #[error("Internal server error: {0}")]
+    InternalServerError(ApiError),
+    /// Any other unsuccessful status.
+    #[error("Unexpected status: {0}")]
+    UnexpectedStatus(ApiError),
+    /// The token was not given this scope, see [`crate::GeniusBuilder::scopes`], the request was not sent.
+    #[error("Missing scope: the token needs the `{0}` scope")]
+    MissingScope(Scope),
+    /// The token or the user agent given to [`crate::GeniusBuilder`] isn't a valid header value, the request was
+    /// not sent.
+    #[error("Invalid header value: the {0} can't be sent in a header")]
+    InvalidHeader(&'static str),
+    /// The disk cache is offline and doesn't have a response for this path, genius.com pages are never cached.
+    #[error("Not in the offline cache: {0}")]
+    OfflineCacheMiss(String),
     #[error("Parse error: {0}")]
     ParseError(String),
+    /// Not a genius.com url or path, see [`crate::links::song_page_url`].
+    #[error("Invalid genius.com url: {0}")]
+    InvalidUrl(String),
+    /// The [`crate::web_page::WebPageLookup`] doesn't have any url.
+    #[error("Empty web page lookup: at least one url must be set")]
+    EmptyLookup,
+    /// The song page doesn't have lyrics in the expected blocks, see [`crate::lyrics::parse_page`].
+    #[error("Lyrics parse error: {0}")]
+    LyricsParseError(String),
+    /// The API didn't return the lyrics of the song with [`crate::lyrics::LyricsStrategy::Api`], they are only sent
+    /// to tokens with the `user-core` level.
+    #[error("Lyrics unavailable from the API for the song {0}")]
+    LyricsUnavailable(u32),
     #[error("Request error: {0}")]
     RequestError(String),
-    #[error("Unauthorized: {0}")]
-    Unauthorized(String),
-    #[error("Not found: {0}")]
-    NotFound(String),
+}
+
+impl GeniusError {
+    /// Map an unsuccessful response to the variant of its status, `body` is read for the message Genius gives.
+    #[must_use]
+    pub fn from_status(status: u16, path: &str, body: &[u8]) -> Self {
+        let body = serde_json::from_slice::<ErrorBody>(body).ok();
+        let errors = body.as_ref().map(ErrorBody::errors).unwrap_or_default();
+        let error = ApiError {
+            status,
+            path: path.to_owned(),
+            message: body.and_then(ErrorBody::message),
+        };
+        match status {
+            400 => Self::BadRequest(error),
+            401 => Self::Unauthorized(error),
+            403 => Self::Forbidden(error),
+            404 => Self::NotFound(error),
+            422 => Self::Validation { errors, error },
+            429 => Self::RateLimited {
+                retry_after: None,
+                error,
+            },
+            500..=599 => Self::InternalServerError(error),
+            _ => Self::UnexpectedStatus(error),
+        }
+    }
+
+    /// Details of the response if this error comes from an unsuccessful status.
+    #[must_use]
+    pub const fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Self::BadRequest(e)
+            | Self::Unauthorized(e)
+            | Self::Forbidden(e)
+            | Self::NotFound(e)
+            | Self::InternalServerError(e)
+            | Self::UnexpectedStatus(e)
+            | Self::Validation { error: e, .. }
+            | Self::RateLimited { error: e, .. } => Some(e),
+            Self::MissingScope(_)
+            | Self::InvalidHeader(_)
+            | Self::OfflineCacheMiss(_)
+            | Self::ParseError(_)
+            | Self::InvalidUrl(_)
+            | Self::EmptyLookup
+            | Self::LyricsParseError(_)
+            | Self::LyricsUnavailable(_)
+            | Self::RequestError(_) => None,
+        }
+    }
+}
+
+/// Details of an unsuccessful response.
+#[derive(Debug, Clone)]
+pub struct ApiError {
+    /// HTTP status code.
+    pub status: u16,
+    /// Path of the request.
+    pub path: String,
+    /// `meta.message` or `error_description` of the response body.
+    pub message: Option<String>,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "status {} on {}", self.status, self.path)?;
+        if let Some(message) = &self.message {
+            write!(f, ": {message}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    meta: Option<ErrorMeta>,
+    response: Option<Value>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ErrorMeta {
+    message: Option<String>,
+}
+
+impl ErrorBody {
+    /// Messages of `response.errors`, a list of messages or the messages of each field.
+    fn errors(&self) -> Vec<String> {
+        let errors = self
+            .response
+            .as_ref()
+            .and_then(|response| response.get("errors"));
+        match errors {
+            Some(Value::Array(messages)) => messages.iter().map(error_message).collect(),
+            Some(Value::Object(fields)) => fields
+                .iter()
+                .flat_map(|(field, messages)| match messages {
+                    Value::Array(messages) => messages
+                        .iter()
+                        .map(|m| format!("{field} {}", error_message(m)))
+                        .collect(),
+                    m => vec![format!("{field} {}", error_message(m))],
+                })
+                .collect(),
+            Some(m) => vec![error_message(m)],
+            None => Vec::new(),
+        }
+    }
+
+    fn message(self) -> Option<String> {
+        self.meta
+            .and_then(|meta| meta.message)
+            .or(self.error_description)
+            .or(self.error)
+    }
+}
+
+fn error_message(value: &Value) -> String {
+    value
+        .as_str()
+        .map_or_else(|| value.to_string(), str::to_owned)
 }
@@ -5,8 +5,8 @@
 //!  Create an API Client at <https://genius.com/developers> and get the token to get Genius API access.
 //! ## Searching for a Song
 //!
-//! ```rust
-//! use genius_rs::Genius;
+//! ```rust,no_run
+//! use genius_rust::Genius;
 //!
 //! #[tokio::main]
 //! async fn main() {
@@ -18,15 +18,15 @@
 //!
 //! ## Getting lyrics
 //!
-//! ```rust
-//! use genius_rs::Genius;
+//! ```rust,no_run
+//! use genius_rust::Genius;
 //!
 //! #[tokio::main]
 //! async fn main() {
 //!     let genius = Genius::new(dotenv::var("TOKEN").unwrap());
 //!     let response = genius.search("Sia").await.unwrap();
 //!     let lyrics = genius.get_lyrics(response[0].result.id).await.unwrap();
-//!     for verse in lyrics {
+//!     for verse in lyrics.lines() {
 //!         println!("{}", verse);
 //!     }
 //! }
@@ -34,46 +34,166 @@
 //!
 //! ## Getting deeper information for a song by id
 //!
-//! ```rust
-//! use genius_rs::Genius;
+//! ```rust,no_run
+//! use genius_rust::{Genius, TextFormat};
 //!
 //! #[tokio::main]
 //! async fn main() {
 //!     let genius = Genius::new(dotenv::var("TOKEN").unwrap());
 //!     let response = genius.search("Weeknd").await.unwrap();
-//!     let song = genius.get_song(response[0].result.id, "plain").await.unwrap();
+//!     let song = genius.get_song(response[0].result.id, TextFormat::PLAIN).await.unwrap();
 //!     println!("{}", song.media.unwrap()[0].url)
 //! }
 //! ```
 
 #![warn(clippy::all, clippy::pedantic, clippy::nursery, clippy::str_to_string)]
-#![allow(clippy::module_name_repetitions, clippy::struct_excessive_bools)]
+#![allow(
+    clippy::module_name_repetitions,
+    clippy::struct_excessive_bools,
+    clippy::pub_underscore_fields
+)]
 
 /// Album response
 pub mod album;
 /// Annotation response
 pub mod annotation;
+/// Artist response
+pub mod artist;
 /// Authentication methods
 pub mod auth;
+/// Blocking client
+#[cfg(feature = "blocking")]
+pub mod blocking;
+/// Client configuration
+pub mod builder;
+/// In-memory response cache
+pub mod cache;
+mod client;
+/// On-disk response cache
+pub mod disk_cache;
+/// DOM text format
+pub mod dom;
 /// Error response
 pub mod error;
+/// genius.com URLs
+pub mod links;
+/// Lyrics scraping
+pub mod lyrics;
+/// Paginated results
+pub mod page;
+/// Client-side rate limit
+pub mod rate_limit;
+/// Retry policy
+pub mod retry;
 /// Search response
 pub mod search;
 /// Song response
 pub mod song;
+/// Text format of bodies
+pub mod text_format;
+/// HTTP transport
+pub mod transport;
 /// User response
 pub mod user;
+/// Web page response
+pub mod web_page;
 
 use album::Album;
+use annotation::{Annotation, AnnotationWithReferent, Referent};
+use artist::Artist;
+pub use builder::GeniusBuilder;
+use cache::{CacheKey, ResponseCache};
+use client::{Config, Lookup};
+use disk_cache::DiskCache;
+use dom::DomNode;
 use error::GeniusError;
-use reqwest::Client;
-use search::Hit;
-use serde::{Serialize, Deserialize};
+use search::{Hit, MultiSearch, Section};
+use serde::{Deserialize, Serialize};
 use song::Song;
+use std::sync::Arc;
+pub use text_format::TextFormat;
+use transport::{HttpRequest, HttpResponse, Transport};
+use user::CurrentUser;
+use web_page::WebPage;
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
+    use crate::retry::RetryPolicy;
+    use crate::transport::{TransportError, TransportErrorKind, TransportFuture};
+    use reqwest::header::{HeaderValue, ETAG, IF_NONE_MATCH, RETRY_AFTER};
+    use reqwest::StatusCode;
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    pub const SONG: &str = include_str!("../tests/fixtures/song.json");
+    pub const ALBUM: &str = include_str!("../tests/fixtures/album.json");
+    pub const ANNOTATION: &str = include_str!("../tests/fixtures/annotation.json");
+    pub const ACCOUNT: &str = include_str!("../tests/fixtures/account.json");
+    pub const ARTIST: &str = include_str!("../tests/fixtures/artist.json");
+    pub const ARTIST_SONGS: &str = include_str!("../tests/fixtures/artist_songs.json");
+    pub const LYRICS: &str = include_str!("../tests/fixtures/lyrics.html");
+    pub const REFERENTS: &str = include_str!("../tests/fixtures/referents.json");
+    pub const SEARCH: &str = include_str!("../tests/fixtures/search.json");
+    pub const SEARCH_MULTI: &str = include_str!("../tests/fixtures/search_multi.json");
+    pub const WEB_PAGE: &str = include_str!("../tests/fixtures/web_page.json");
+
+    /// Requests received by a [`FakeTransport`].
+    pub type Requests = Arc<Mutex<Vec<HttpRequest>>>;
+
+    /// Transport answering with canned responses in order.
+    pub struct FakeTransport {
+        responses: Mutex<VecDeque<Result<HttpResponse, TransportError>>>,
+        requests: Requests,
+    }
+
+    impl Transport for FakeTransport {
+        fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+            self.requests.lock().unwrap().push(request);
+            let response = self
+                .responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("No response left in the fake transport");
+            Box::pin(async move { response })
+        }
+    }
+
+    /// Client built with `builder` answering with `responses`.
+    pub fn fake_genius(
+        builder: GeniusBuilder,
+        responses: Vec<Result<HttpResponse, TransportError>>,
+    ) -> (Genius, Requests) {
+        let requests = Requests::default();
+        let transport = FakeTransport {
+            responses: Mutex::new(responses.into()),
+            requests: Arc::clone(&requests),
+        };
+        (
+            builder.token("token").transport(transport).build(),
+            requests,
+        )
+    }
+
+    /// Urls of the requests received by a [`FakeTransport`].
+    pub fn urls(requests: &Requests) -> Vec<String> {
+        requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|request| request.url.clone())
+            .collect()
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    pub fn respond(status: u16, body: &str) -> Result<HttpResponse, TransportError> {
+        Ok(HttpResponse::new(
+            StatusCode::from_u16(status).unwrap(),
+            body,
+        ))
+    }
 
     #[tokio::test]
     async fn search_test() {
@@ -86,181 +206,276 @@ mod tests {
     async fn get_lyrics_test() {
         let genius = Genius::new(dotenv::var("TOKEN").unwrap());
         let lyrics = genius.get_lyrics(1).await.unwrap();
-        for verse in lyrics {
-            println!("{}", verse);
+        for verse in lyrics.lines() {
+            println!("{verse}");
         }
     }
 
+    #[test]
+    fn genius_is_shareable_test() {
+        fn assert_shareable<T: Clone + Send + Sync>() {}
+        assert_shareable::<Genius>();
+    }
+
     #[tokio::test]
     async fn get_song_test() {
         let genius = Genius::new(dotenv::var("TOKEN").unwrap());
-        genius.get_song(378_195, "plain").await.unwrap();
+        genius.get_song(378_195, TextFormat::PLAIN).await.unwrap();
     }
 
     #[tokio::test]
     async fn get_album_test() {
         let genius = Genius::new(dotenv::var("TOKEN").unwrap());
-        genius.get_album(27501, "plain").await.unwrap();
+        genius.get_album(27501, TextFormat::PLAIN).await.unwrap();
     }
-}
 
-const URL: &str = "https://api.genius.com";
+    #[tokio::test]
+    async fn error_offline_test() {
+        let (genius, _) = fake_genius(
+            Genius::builder(),
+            vec![
+                respond(401, r#"{"error":"invalid_token"}"#),
+                respond(404, r#"{"meta":{"status":404,"message":"Not found"}}"#),
+                respond(200, "not json"),
+                respond(200, r#"{"response":{}}"#),
+                Err(TransportError::new(
+                    TransportErrorKind::Other,
+                    "broken pipe",
+                )),
+            ],
+        );
+        assert!(matches!(
+            genius.search("Sia").await,
+            Err(GeniusError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            genius.get_song(0, TextFormat::PLAIN).await,
+            Err(GeniusError::NotFound(_))
+        ));
+        assert!(matches!(
+            genius.get_album(1, TextFormat::PLAIN).await,
+            Err(GeniusError::ParseError(_))
+        ));
+        assert!(matches!(
+            genius.get_album(1, TextFormat::PLAIN).await,
+            Err(GeniusError::ParseError(_))
+        ));
+        assert!(matches!(
+            genius.search("Sia").await,
+            Err(GeniusError::RequestError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn invalid_header_offline_test() {
+        let (genius, requests) = fake_genius(Genius::builder().user_agent("my-app\n1.0"), vec![]);
+        assert!(matches!(
+            genius.search("Sia").await,
+            Err(GeniusError::InvalidHeader("user agent"))
+        ));
+        let transport = FakeTransport {
+            responses: Mutex::default(),
+            requests: Arc::clone(&requests),
+        };
+        let genius = Genius::builder()
+            .token("my\ntoken")
+            .transport(transport)
+            .build();
+        assert!(matches!(
+            genius.get_song(1, TextFormat::PLAIN).await,
+            Err(GeniusError::InvalidHeader("token"))
+        ));
+        assert!(requests.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn retry_offline_test() {
+        let policy = RetryPolicy::default().base_delay(Duration::from_millis(1));
+        let mut rate_limited = HttpResponse::new(StatusCode::TOO_MANY_REQUESTS, "");
+        rate_limited
+            .headers
+            .insert(RETRY_AFTER, HeaderValue::from_static("0"));
+        let (genius, requests) = fake_genius(
+            Genius::builder().retry_policy(policy),
+            vec![
+                respond(503, ""),
+                Err(TransportError::new(
+                    TransportErrorKind::Timeout,
+                    "timed out",
+                )),
+                respond(200, SONG),
+                Ok(rate_limited.clone()),
+                Ok(rate_limited.clone()),
+                Ok(rate_limited),
+            ],
+        );
+        genius.get_song(378_195, TextFormat::PLAIN).await.unwrap();
+        assert_eq!(requests.lock().unwrap().len(), 3);
+        assert!(matches!(
+            genius.get_song(378_195, TextFormat::PLAIN).await,
+            Err(GeniusError::RateLimited {
+                retry_after: Some(_),
+                ..
+            })
+        ));
+        assert_eq!(requests.lock().unwrap().len(), 6);
+    }
+
+    #[tokio::test]
+    async fn cache_offline_test() {
+        let (genius, requests) = fake_genius(
+            Genius::builder().cache(ResponseCache::new(10, Duration::from_secs(30))),
+            vec![respond(200, SONG)],
+        );
+        genius.get_song(378_195, TextFormat::PLAIN).await.unwrap();
+        genius.get_song(378_195, TextFormat::PLAIN).await.unwrap();
+        assert_eq!(requests.lock().unwrap().len(), 1);
+        assert_eq!(genius.cache().unwrap().stats().hits, 1);
+    }
+
+    #[tokio::test]
+    async fn disk_cache_offline_test() {
+        let dir = std::env::temp_dir().join(format!("genius-rs-lib-{}", std::process::id()));
+        let mut stored = HttpResponse::new(StatusCode::OK, SONG);
+        stored
+            .headers
+            .insert(ETAG, HeaderValue::from_static("\"v1\""));
+        let (genius, requests) = fake_genius(
+            Genius::builder().disk_cache(DiskCache::new(&dir)),
+            vec![Ok(stored), respond(304, "")],
+        );
+        genius.get_song(378_195, TextFormat::PLAIN).await.unwrap();
+        let song = genius.get_song(378_195, TextFormat::PLAIN).await.unwrap();
+        assert_eq!(song.id, 378_195);
+        assert_eq!(requests.lock().unwrap()[1].headers[IF_NONE_MATCH], "\"v1\"");
+
+        let (offline, _) = fake_genius(
+            Genius::builder().disk_cache(DiskCache::new(&dir).offline(true)),
+            vec![],
+        );
+        offline.get_song(378_195, TextFormat::PLAIN).await.unwrap();
+        assert!(matches!(
+            offline.get_album(1, TextFormat::PLAIN).await,
+            Err(GeniusError::OfflineCacheMiss(_))
+        ));
+        assert!(matches!(
+            offline.resolve_song_id("/Sia-chandelier-lyrics").await,
+            Err(GeniusError::OfflineCacheMiss(url)) if url == "https://genius.com/Sia-chandelier-lyrics"
+        ));
+        let (other_api, _) = fake_genius(
+            Genius::builder()
+                .api_url("https://example.com")
+                .disk_cache(DiskCache::new(&dir).offline(true)),
+            vec![],
+        );
+        assert!(matches!(
+            other_api.get_song(378_195, TextFormat::PLAIN).await,
+            Err(GeniusError::OfflineCacheMiss(_))
+        ));
+        DiskCache::new(&dir).clear().unwrap();
+    }
+}
 
 /// The main hub for interacting with the Genius API
+///
+/// Cloning is cheap and clones share their configuration and rate limit, so a client can be cloned into each task.
+#[derive(Clone)]
 pub struct Genius {
-    reqwest: Client,
-    token: String,
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    transport: Box<dyn Transport>,
+    config: Config,
 }
 
 impl Genius {
     /// Create an API Client at <https://genius.com/developers> and get the token to get basic Genius API access. The token will be level client.
     #[must_use]
     pub fn new(token: String) -> Self {
-        Self {
-            reqwest: Client::new(),
-            token,
-        }
+        Self::builder().token(token).build()
     }
 
-    /// Search for a song in Genius the result will be [`search::Hit`]
-    ///
-    /// # Errors
-    ///
-    /// Will return [`GeniusError::RequestError`] if the request fails.
-    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
-    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
-    /// Will return [`GeniusError::NotFound`] if the field `hits` is empty in the response if this occurs you should contact the developer.
-    pub async fn search(&self, q: &str) -> Result<Vec<Hit>, GeniusError> {
-        let request = self
-            .reqwest
-            .get(format!("{}/search?q={}", URL, q))
-            .bearer_auth(&self.token)
-            .send()
-            .await;
-        let request = match request {
-            Ok(request) => request.json::<Response>().await,
-            Err(e) => return Err(GeniusError::RequestError(e.to_string())),
-        };
-        let res = match request {
-            Ok(res) => res.response.hits,
-            Err(e) => {
-                if let Some(status) = e.status() {
-                    if status.is_client_error() {
-                        return Err(GeniusError::Unauthorized(e.to_string()));
-                    }
-                }
-                return Err(GeniusError::ParseError(e.to_string()));
-            }
-        };
-        match res {
-            Some(res) => Ok(res),
-            None => Err(GeniusError::NotFound("Hits not found in data".to_owned())),
-        }
+    /// Configure a client with a custom base URL, user agent, timeout, headers, [`reqwest::Client`] or [`Transport`], see [`GeniusBuilder`].
+    #[must_use]
+    pub fn builder() -> GeniusBuilder {
+        GeniusBuilder::new()
     }
 
-    /// Get lyrics with an url of genius song like: <https://genius.com/Sia-chandelier-lyrics>
-    ///
-    /// # Errors
-    ///
-    /// Will return [`GeniusError::RequestError`] if the request fails.
-    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
-    /// Will return [`GeniusError::NotFound`] if the field `hits` is empty in the response if this occurs you should contact the developer.
-    pub async fn get_lyrics(&self, id: u32) -> Result<Vec<String>, GeniusError> {
-        let request = self
-            .reqwest
-            .get(format!("https://lyrics.altart.tk/api/lyrics/{}", id))
-            .send()
-            .await;
-        let request = match request {
-            Ok(request) => request.json::<Body>().await,
-            Err(e) => return Err(GeniusError::RequestError(e.to_string())),
-        };
-        let plain = match request {
-            Ok(res) => res.plain,
-            Err(e) => {
-                if let Some(status) = e.status() {
-                    if status.is_client_error() {
-                        return Err(GeniusError::Unauthorized(e.to_string()));
-                    }
-                }
-                return Err(GeniusError::ParseError(e.to_string()));
-            }
-        };
-        match plain {
-            Some(text) => Ok(text.split('\n').map(String::from).collect::<Vec<String>>()),
-            None => Err(GeniusError::NotFound("Lyrics not found in data".to_owned())),
-        }
+    /// The response cache configured with [`GeniusBuilder::cache`].
+    #[must_use]
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.inner.config.cache.as_ref()
     }
 
-    /// Get deeper information from a song by it's id, `text_format` is the field for the format of text bodies related to the document. Available text formats are `plain` and `html`
-    ///
-    /// # Errors
-    ///
-    /// Will return [`GeniusError::RequestError`] if the request fails.
-    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
-    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
-    /// Will return [`GeniusError::NotFound`] if the field `hits` is empty in the response if this occurs you should contact the developer.
-    pub async fn get_song(&self, id: u32, text_format: &str) -> Result<Song, GeniusError> {
-        let request = self
-            .reqwest
-            .get(format!("{}/songs/{}?text_format={}", URL, id, text_format))
-            .bearer_auth(&self.token)
-            .send()
-            .await;
-        let request = match request {
-            Ok(request) => request.json::<Response>().await,
-            Err(e) => return Err(GeniusError::RequestError(e.to_string())),
-        };
-        let res = match request {
-            Ok(res) => res.response.song,
-            Err(e) => {
-                if let Some(status) = e.status() {
-                    if status.is_client_error() {
-                        return Err(GeniusError::Unauthorized(e.to_string()));
-                    }
-                }
-                return Err(GeniusError::ParseError(e.to_string()));
+    /// The on-disk cache configured with [`GeniusBuilder::disk_cache`].
+    #[must_use]
+    pub fn disk_cache(&self) -> Option<&DiskCache> {
+        self.inner.config.disk_cache.as_ref()
+    }
+
+    /// `GET` an API `path` and parse the `response` field of the body, going through the caches when enabled.
+    async fn get(&self, path: &str) -> Result<BlobResponse, GeniusError> {
+        let config = &self.inner.config;
+        let key = CacheKey::path(path);
+        let body = match config.lookup(&key)? {
+            Lookup::Cached(body) => body,
+            Lookup::Request { request, stored } => {
+                let response = self.send(request, path).await?;
+                config.store(&key, stored, response)
             }
         };
-        match res {
-            Some(res) => Ok(res),
-            None => Err(GeniusError::NotFound("Song not found in data".to_owned())),
-        }
+        client::parse_response(&body)
     }
-    /// Get deeper information from a album by it's id, `text_format` is the field for the format of text bodies related to the document. Available text formats are `plain` and `html`
-    ///
-    /// # Errors
-    ///
-    /// Will return [`GeniusError::RequestError`] if the request fails.
-    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
-    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON if this occurs you should contact the developer.
-    /// Will return [`GeniusError::NotFound`] if the field `hits` is empty in the response if this occurs you should contact the developer.
-    pub async fn get_album(&self, id: u32, text_format: &str) -> Result<Album, GeniusError> {
-        let request = self
-            .reqwest
-            .get(format!("{}/albums/{}?text_format={}", URL, id, text_format))
-            .bearer_auth(&self.token)
-            .send()
-            .await;
-        let request = match request {
-            Ok(request) => request.json::<Response>().await,
-            Err(e) => return Err(GeniusError::RequestError(e.to_string())),
-        };
-        let res = match request {
-            Ok(res) => res.response.album,
-            Err(e) => return Err(GeniusError::ParseError(e.to_string())),
-        };
-        match res {
-            Some(res) => Ok(res),
-            None => Err(GeniusError::NotFound("Album not found in data".to_owned())),
+
+    /// Get the HTML of a genius.com page, the token is not sent and the caches are not used.
+    async fn get_page(&self, url: &str) -> Result<String, GeniusError> {
+        let request = self.inner.config.page_request(url)?;
+        let response = self.send(request, url).await?;
+        Ok(String::from_utf8_lossy(&response.body).into_owned())
+    }
+
+    /// Send a request that must not be cached, like a scoped request changing a resource or reading the account, and
+    /// parse the `response` field of the body.
+    async fn send_uncached(
+        &self,
+        request: HttpRequest,
+        path: &str,
+    ) -> Result<BlobResponse, GeniusError> {
+        let response = self.send(request, path).await?;
+        client::parse_response(&response.body)
+    }
+
+    /// Send a request and read its body, waiting for the rate limit and retrying as the [`RetryPolicy`] allows.
+    async fn send(&self, request: HttpRequest, path: &str) -> Result<HttpResponse, GeniusError> {
+        let config = &self.inner.config;
+        config.check_headers()?;
+        let mut attempt = 1;
+        loop {
+            if let Some(rate_limit) = &config.rate_limit {
+                rate_limit.acquire().await;
+            }
+            let outcome = self.inner.transport.send(request.clone()).await;
+            let failure = match config.check(&request, outcome, path) {
+                Ok(response) => return Ok(response),
+                Err(failure) => failure,
+            };
+            match config.retry_delay(attempt, &failure) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(failure.error),
+            }
+            attempt += 1;
         }
     }
 }
 
+/// Text body in each of the requested [`TextFormat`]s.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Body {
     pub plain: Option<String>,
     pub html: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dom: Option<DomNode>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -280,4 +495,53 @@ struct BlobResponse {
     song: Option<Song>,
     hits: Option<Vec<Hit>>,
     album: Option<Album>,
+    artist: Option<Artist>,
+    songs: Option<Vec<Song>>,
+    next_page: Option<u32>,
+    referents: Option<Vec<Referent>>,
+    annotation: Option<Annotation>,
+    referent: Option<Referent>,
+    user: Option<CurrentUser>,
+    web_page: Option<WebPage>,
+    sections: Option<Vec<Section>>,
+}
+
+impl BlobResponse {
+    fn annotation(self) -> Result<Annotation, GeniusError> {
+        self.annotation
+            .ok_or_else(|| GeniusError::ParseError("Annotation not found in data".to_owned()))
+    }
+
+    fn hits(self) -> Result<Vec<Hit>, GeniusError> {
+        self.hits
+            .ok_or_else(|| GeniusError::ParseError("Hits not found in data".to_owned()))
+    }
+
+    fn multi_search(self) -> Result<MultiSearch, GeniusError> {
+        let sections = self
+            .sections
+            .ok_or_else(|| GeniusError::ParseError("Sections not found in data".to_owned()))?;
+        MultiSearch::from_sections(sections)
+    }
+
+    fn user(self) -> Result<CurrentUser, GeniusError> {
+        self.user
+            .ok_or_else(|| GeniusError::ParseError("User not found in data".to_owned()))
+    }
+
+    fn web_page(self) -> Result<WebPage, GeniusError> {
+        self.web_page
+            .ok_or_else(|| GeniusError::ParseError("Web page not found in data".to_owned()))
+    }
+
+    fn annotation_with_referent(mut self) -> Result<AnnotationWithReferent, GeniusError> {
+        let referent = self.referent.take();
+        let annotation = self.annotation()?;
+        let referent = referent
+            .ok_or_else(|| GeniusError::ParseError("Referent not found in data".to_owned()))?;
+        Ok(AnnotationWithReferent {
+            annotation,
+            referent,
+        })
+    }
 }
@@ -0,0 +1,243 @@
+use reqwest::Url;
+use scraper::{ElementRef, Html};
+
+use crate::error::GeniusError;
+use crate::lyrics::{self, Lyrics, LyricsSource};
+use crate::song::Song;
+use crate::{Genius, TextFormat};
+
+#[cfg(test)]
+#[allow(clippy::items_after_test_module)]
+mod test {
+    use crate::error::GeniusError;
+    use crate::links::{parse_song_id, song_page_url, song_url};
+    use crate::lyrics::SectionKind;
+    use crate::tests::{fake_genius, respond, urls, LYRICS, SONG};
+    use crate::{Genius, TextFormat};
+
+    #[test]
+    fn song_page_url_test() {
+        let canonical = Some("https://genius.com/Sia-chandelier-lyrics".to_owned());
+        for input in [
+            "https://genius.com/Sia-chandelier-lyrics",
+            "http://www.genius.com/Sia-chandelier-lyrics?referrer=search#about",
+            "genius.com/Sia-chandelier-lyrics",
+            " /Sia-chandelier-lyrics ",
+        ] {
+            assert_eq!(song_page_url(input), canonical, "{input}");
+        }
+        for input in [
+            "https://example.com/Sia-chandelier-lyrics",
+            "https://genius.com/",
+            "Sia-chandelier-lyrics",
+            "",
+        ] {
+            assert_eq!(song_page_url(input), None, "{input}");
+        }
+        assert_eq!(song_url(378_195), "https://genius.com/songs/378195");
+    }
+
+    #[test]
+    fn parse_song_id_test() {
+        let page = r#"<html><head><meta content="/songs/378195" name="newrelic-resource-path"></head></html>"#;
+        assert_eq!(parse_song_id(page), Some(378_195));
+        let page = r#"<html><head><meta property="twitter:app:url:iphone" content="genius://songs/378195"></head></html>"#;
+        assert_eq!(parse_song_id(page), Some(378_195));
+        let page = r#"<html><head><meta content="/artists/16775" name="newrelic-resource-path"></head></html>"#;
+        assert_eq!(parse_song_id(page), None);
+    }
+
+    #[tokio::test]
+    async fn get_lyrics_by_url_offline_test() {
+        let (genius, requests) = fake_genius(
+            Genius::builder(),
+            vec![
+                respond(200, LYRICS),
+                respond(200, SONG),
+                respond(200, LYRICS),
+            ],
+        );
+        let lyrics = genius
+            .get_lyrics_by_url("genius.com/Sia-chandelier-lyrics?referrer=search")
+            .await
+            .unwrap();
+        assert_eq!(lyrics.sections[0].kind, SectionKind::Intro);
+        assert_eq!(
+            genius
+                .resolve_song_id("/Sia-chandelier-lyrics")
+                .await
+                .unwrap(),
+            378_195
+        );
+        let urls = urls(&requests);
+        assert_eq!(
+            urls,
+            [
+                "https://genius.com/Sia-chandelier-lyrics",
+                "https://api.genius.com/songs/378195?text_format=plain",
+                "https://genius.com/Sia-chandelier-lyrics",
+            ]
+        );
+        assert!(matches!(
+            genius
+                .get_song_by_url("https://example.com/", TextFormat::PLAIN)
+                .await,
+            Err(GeniusError::InvalidUrl(_))
+        ));
+    }
+}
+
+/// Base URL of the genius.com pages.
+pub const GENIUS_URL: &str = "https://genius.com";
+
+/// Url of the page of the song `id`, genius.com redirects it to the canonical [`Song::url`].
+#[must_use]
+pub fn song_url(id: u32) -> String {
+    format!("{GENIUS_URL}/songs/{id}")
+}
+
+/// Url of the page of the artist `id`, genius.com redirects it to the canonical artist url.
+#[must_use]
+pub fn artist_url(id: u32) -> String {
+    format!("{GENIUS_URL}/artists/{id}")
+}
+
+/// Url of the page of the album `id`, genius.com redirects it to the canonical album url.
+#[must_use]
+pub fn album_url(id: u32) -> String {
+    format!("{GENIUS_URL}/albums/{id}")
+}
+
+/// Url of the annotation `id`, the same as its `share_url`.
+#[must_use]
+pub fn annotation_url(id: u32) -> String {
+    format!("{GENIUS_URL}/{id}")
+}
+
+/// Url of a song page from a genius.com url with or without scheme, or from a path like [`Song::path`].
+/// The query and the fragment are dropped, `None` is returned for another host or an empty path.
+#[must_use]
+pub fn song_page_url(input: &str) -> Option<String> {
+    song_page_path(input).map(|path| format!("{GENIUS_URL}{path}"))
+}
+
+/// Path of the song page of [`song_page_url`].
+fn song_page_path(input: &str) -> Option<String> {
+    let input = input.trim();
+    let url = if input.starts_with('/') {
+        Url::parse(GENIUS_URL).ok()?.join(input).ok()?
+    } else if input.starts_with("http://") || input.starts_with("https://") {
+        Url::parse(input).ok()?
+    } else if input.starts_with("genius.com/") || input.starts_with("www.genius.com/") {
+        Url::parse(&format!("https://{input}")).ok()?
+    } else {
+        return None;
+    };
+    let host = url.host_str()?;
+    if (host != "genius.com" && host != "www.genius.com") || url.path() == "/" {
+        return None;
+    }
+    Some(url.path().to_owned())
+}
+
+/// Id of the song of a genius.com song page, read from its `newrelic-resource-path` or app link `<meta>` tags.
+#[must_use]
+pub fn parse_song_id(html: &str) -> Option<u32> {
+    let document = Html::parse_document(html);
+    document
+        .root_element()
+        .descendants()
+        .filter_map(ElementRef::wrap)
+        .filter(|element| element.value().name() == "meta")
+        .find_map(|meta| {
+            let meta = meta.value();
+            let name = meta.attr("name").or_else(|| meta.attr("property"))?;
+            let content = meta.attr("content")?;
+            let id = match name {
+                "newrelic-resource-path" => content.strip_prefix("/songs/"),
+                _ if name.starts_with("twitter:app:url:") || name.starts_with("al:") => {
+                    content.strip_prefix("genius://songs/")
+                }
+                _ => None,
+            };
+            id?.parse().ok()
+        })
+}
+
+/// `input` as the url of a song page on `web_url`, or [`GeniusError::InvalidUrl`]. Besides the inputs of
+/// [`song_page_url`], a url on `web_url` itself is accepted.
+pub(crate) fn page_url(input: &str, web_url: &str) -> Result<String, GeniusError> {
+    song_page_path(input)
+        .or_else(|| {
+            let path = input.trim().strip_prefix(web_url)?;
+            let path = path.split(['?', '#']).next().unwrap_or_default();
+            (path.len() > 1 && path.starts_with('/')).then(|| path.to_owned())
+        })
+        .map(|path| format!("{web_url}{path}"))
+        .ok_or_else(|| GeniusError::InvalidUrl(input.to_owned()))
+}
+
+/// `url` of the API, like [`Song::url`], with its path moved onto `web_url`.
+pub(crate) fn on_web_url(url: &str, web_url: &str) -> String {
+    Url::parse(url).map_or_else(|_| url.to_owned(), |url| format!("{web_url}{}", url.path()))
+}
+
+/// Song id of a song `page`, or [`GeniusError::ParseError`].
+pub(crate) fn page_song_id(page: &str) -> Result<u32, GeniusError> {
+    parse_song_id(page)
+        .ok_or_else(|| GeniusError::ParseError("Song id not found in the page".to_owned()))
+}
+
+impl Genius {
+    /// Get the id of the song of a genius.com url or path like `https://genius.com/Sia-chandelier-lyrics` or
+    /// `/Sia-chandelier-lyrics`, the page is requested to read the id, see [`parse_song_id`].
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::InvalidUrl`] if `url` is not a genius.com url or path, see [`song_page_url`].
+    /// Will return [`GeniusError::RequestError`] if the request of the page fails, the status errors are mapped as described in [`GeniusError`].
+    /// Will return [`GeniusError::ParseError`] if the page doesn't have the id of a song.
+    pub async fn resolve_song_id(&self, url: &str) -> Result<u32, GeniusError> {
+        let page = self
+            .get_page(&page_url(url, &self.inner.config.web_url)?)
+            .await?;
+        page_song_id(&page)
+    }
+
+    /// Get the song of a genius.com url or path, `text_format` is the format of text bodies related to the document.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::resolve_song_id`] and [`Self::get_song`].
+    pub async fn get_song_by_url(
+        &self,
+        url: &str,
+        text_format: TextFormat,
+    ) -> Result<Song, GeniusError> {
+        let id = self.resolve_song_id(url).await?;
+        self.get_song(id, text_format).await
+    }
+
+    /// Get the lyrics of the song of a genius.com url or path, the page is requested only once and the
+    /// [`crate::lyrics::LyricsStrategy`] decides if its lyrics or the ones of the API are used.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::resolve_song_id`] and [`Self::get_lyrics`].
+    pub async fn get_lyrics_by_url(&self, url: &str) -> Result<Lyrics, GeniusError> {
+        let page = self
+            .get_page(&page_url(url, &self.inner.config.web_url)?)
+            .await?;
+        let song = self
+            .get_song(page_song_id(&page)?, TextFormat::PLAIN)
+            .await?;
+        if let Some(lyrics) = lyrics::api_lyrics(&song, self.inner.config.lyrics_strategy)? {
+            return Ok(lyrics);
+        }
+        Ok(Lyrics::new(
+            lyrics::parse_page(&page)?,
+            &song,
+            LyricsSource::Page,
+        ))
+    }
+}
@@ -0,0 +1,852 @@
+use ego_tree::NodeRef;
+use scraper::{ElementRef, Html, Node};
+use serde::{Deserialize, Serialize};
+
+use crate::annotation::Referent;
+use crate::artist::Artist;
+use crate::error::GeniusError;
+use crate::links;
+use crate::song::Song;
+use crate::{Genius, TextFormat};
+
+#[cfg(test)]
+mod test {
+    use crate::annotation::Referent;
+    use crate::client;
+    use crate::error::GeniusError;
+    use crate::lyrics::{
+        parse_page, parse_plain, AnnotatedLyrics, Lyrics, LyricsSource, LyricsStrategy, Performer,
+        SectionKind,
+    };
+    use crate::song::Song;
+    use crate::tests::{fake_genius, respond, urls, LYRICS, REFERENTS, SONG};
+    use crate::Genius;
+    use reqwest::header::AUTHORIZATION;
+
+    fn song() -> Song {
+        let mut song: serde_json::Value = serde_json::from_str(SONG).unwrap();
+        let mut featured = song["response"]["song"]["primary_artist"].clone();
+        featured["id"] = 1_045_210.into();
+        featured["name"] = "Ariana Grande".into();
+        song["response"]["song"]["featured_artists"] = serde_json::json!([featured]);
+        client::parse_response(song.to_string().as_bytes())
+            .unwrap()
+            .song
+            .unwrap()
+    }
+
+    #[test]
+    fn lyrics_test() {
+        let lines = [
+            "Intro line",
+            "",
+            "[Verse 2: Ariana Grande & Sia, The Weeknd]",
+            "First line",
+            "Second line",
+            "",
+            "[Pre-Chorus]",
+            "Pre line",
+            "",
+            "[Chorus x2]",
+            "Chorus line",
+            "",
+            "[Post-Chorus: Sia]",
+            "Post line",
+        ];
+        let lyrics = Lyrics::new(
+            lines.iter().map(|&line| line.to_owned()).collect(),
+            &song(),
+            LyricsSource::Page,
+        );
+        let kinds: Vec<_> = lyrics.sections.iter().map(|s| s.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            [
+                SectionKind::Other(String::new()),
+                SectionKind::Verse,
+                SectionKind::PreChorus,
+                SectionKind::Chorus,
+                SectionKind::Other("Post-Chorus".to_owned()),
+            ]
+        );
+        let verse = &lyrics.sections[1];
+        assert_eq!(verse.ordinal, Some(2));
+        assert_eq!(verse.lines, ["First line", "Second line"]);
+        assert_eq!(
+            verse.performers,
+            [
+                Performer {
+                    name: "Ariana Grande".to_owned(),
+                    artist_id: Some(1_045_210),
+                },
+                Performer {
+                    name: "Sia".to_owned(),
+                    artist_id: Some(16_775),
+                },
+                Performer {
+                    name: "The Weeknd".to_owned(),
+                    artist_id: None,
+                },
+            ]
+        );
+        assert_eq!(lyrics.sections[3].ordinal, None);
+        assert!(lyrics.sections[2].performers.is_empty());
+        assert_eq!(lyrics.lines(), lines);
+    }
+
+    #[test]
+    fn performers_test() {
+        let mut song = song();
+        song.featured_artists.as_mut().unwrap()[0].name = "Simon & Garfunkel".to_owned();
+        let lyrics = Lyrics::new(
+            vec!["[Verse 1: simon & garfunkel & Sia, Simon]".to_owned()],
+            &song,
+            LyricsSource::Page,
+        );
+        let performers: Vec<_> = lyrics.sections[0]
+            .performers
+            .iter()
+            .map(|performer| (performer.name.as_str(), performer.artist_id))
+            .collect();
+        assert_eq!(
+            performers,
+            [
+                ("simon & garfunkel", Some(1_045_210)),
+                ("Sia", Some(16_775)),
+                ("Simon", None),
+            ]
+        );
+    }
+
+    fn referent(id: u32, fragment: &str, annotation_id: u32) -> Referent {
+        let referents: serde_json::Value = serde_json::from_str(REFERENTS).unwrap();
+        let mut referent = referents["response"]["referents"][0].clone();
+        referent["id"] = id.into();
+        referent["fragment"] = fragment.into();
+        referent["range"]["content"] = fragment.into();
+        referent["annotations"][0]["id"] = annotation_id.into();
+        serde_json::from_value(referent).unwrap()
+    }
+
+    #[test]
+    fn annotated_lyrics_test() {
+        let lines = [
+            "[Chorus]",
+            "Swing from the chandelier",
+            "Like it doesn't exist",
+        ];
+        let lyrics = Lyrics::new(
+            lines.iter().map(|&line| line.to_owned()).collect(),
+            &song(),
+            LyricsSource::Page,
+        );
+        let referents = [
+            referent(1, "the chandelier\nLike it", 10),
+            referent(2, "chandelier", 20),
+            referent(3, "not in the song", 30),
+        ];
+        let annotated = AnnotatedLyrics::new(lyrics, &referents);
+        assert!(annotated.lines[0].spans[0].annotation_ids.is_empty());
+        let spans: Vec<_> = annotated
+            .annotated_spans()
+            .map(|span| (span.line, span.text, span.annotation_ids.to_vec()))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                (1, "the ", vec![10]),
+                (1, "chandelier", vec![10, 20]),
+                (2, "Like it", vec![10]),
+            ]
+        );
+        assert_eq!(annotated.lines[1].annotation_ids(), [10, 20]);
+        assert!(annotated.lines[0].annotation_ids().is_empty());
+    }
+
+    #[test]
+    fn parse_page_test() {
+        let lines = parse_page(LYRICS).unwrap();
+        assert_eq!(
+            lines,
+            [
+                "[Intro]",
+                "Party girls don't get hurt",
+                "Can't feel anything, when will I learn?",
+                "I push it down, push it down",
+                "",
+                "[Verse 1]",
+                "I'm the one \"for a good time call\"",
+                "Phone's blowin' up, they're ringin' my doorbell",
+                "I feel the love, feel the love",
+                "",
+                "[Pre-Chorus]",
+                "1, 2, 3, 1, 2, 3, drink",
+                "1, 2, 3, 1, 2, 3, drink",
+                "Throw 'em back 'til I lose count",
+                "",
+                "[Chorus]",
+                "I'm gonna swing from the chandelier, from the chandelier",
+                "I'm gonna live like tomorrow doesn't exist",
+                "Like it doesn't exist & I'll fly",
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_plain_test() {
+        let plain = "[Intro]\r\nParty girls don't get hurt\n\n\n[Verse 1]\n  I'm the one  \n";
+        assert_eq!(
+            parse_plain(plain),
+            [
+                "[Intro]",
+                "Party girls don't get hurt",
+                "",
+                "[Verse 1]",
+                "I'm the one"
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_page_error_test() {
+        let page = "<html><body><div>This song is an instrumental</div></body></html>";
+        assert!(matches!(
+            parse_page(page),
+            Err(GeniusError::LyricsParseError(_))
+        ));
+        let page = r#"<div data-lyrics-container="true"><br/><br/></div>"#;
+        assert!(matches!(
+            parse_page(page),
+            Err(GeniusError::LyricsParseError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_lyrics_offline_test() {
+        let (genius, requests) = fake_genius(
+            Genius::builder(),
+            vec![respond(200, SONG), respond(200, LYRICS)],
+        );
+        let lyrics = genius.get_lyrics(378_195).await.unwrap();
+        assert_eq!(lyrics.source, LyricsSource::Page);
+        assert_eq!(lyrics.sections[0].kind, SectionKind::Intro);
+        assert_eq!(
+            lyrics.lines()[..2],
+            ["[Intro]", "Party girls don't get hurt"]
+        );
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(requests[1].url, "https://genius.com/Sia-chandelier-lyrics");
+        assert!(!requests[1].headers.contains_key(AUTHORIZATION));
+
+        let (genius, _) = fake_genius(
+            Genius::builder(),
+            vec![respond(200, SONG), respond(200, "<html></html>")],
+        );
+        assert!(matches!(
+            genius.get_lyrics(378_195).await,
+            Err(GeniusError::LyricsParseError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn lyrics_strategy_offline_test() {
+        let mut song: serde_json::Value = serde_json::from_str(SONG).unwrap();
+        song["response"]["song"]["lyrics"] =
+            serde_json::json!({ "plain": "[Intro]\nParty girls don't get hurt" });
+        let song = song.to_string();
+
+        let (genius, requests) = fake_genius(Genius::builder(), vec![respond(200, &song)]);
+        let lyrics = genius.get_lyrics(378_195).await.unwrap();
+        assert_eq!(lyrics.source, LyricsSource::Api);
+        assert_eq!(lyrics.sections[0].kind, SectionKind::Intro);
+        assert_eq!(lyrics.sections[0].lines, ["Party girls don't get hurt"]);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        let (genius, _) = fake_genius(
+            Genius::builder().lyrics_strategy(LyricsStrategy::Page),
+            vec![respond(200, &song), respond(200, LYRICS)],
+        );
+        let lyrics = genius.get_lyrics(378_195).await.unwrap();
+        assert_eq!(lyrics.source, LyricsSource::Page);
+        assert_eq!(lyrics.sections.len(), 4);
+
+        let (genius, _) = fake_genius(
+            Genius::builder().lyrics_strategy(LyricsStrategy::Api),
+            vec![respond(200, SONG)],
+        );
+        assert!(matches!(
+            genius.get_lyrics(378_195).await,
+            Err(GeniusError::LyricsUnavailable(378_195))
+        ));
+    }
+
+    #[tokio::test]
+    async fn web_url_offline_test() {
+        let (genius, requests) = fake_genius(
+            Genius::builder()
+                .api_url("http://localhost:8080")
+                .web_url("http://localhost:8081/"),
+            vec![
+                respond(200, SONG),
+                respond(200, LYRICS),
+                respond(200, LYRICS),
+                respond(200, LYRICS),
+            ],
+        );
+        let lyrics = genius.get_lyrics(378_195).await.unwrap();
+        assert_eq!(lyrics.sections.len(), 4);
+        for url in [
+            "/Sia-chandelier-lyrics",
+            "http://localhost:8081/Sia-chandelier-lyrics?referrer=search",
+        ] {
+            assert_eq!(genius.resolve_song_id(url).await.unwrap(), 378_195);
+        }
+        assert_eq!(
+            urls(&requests),
+            [
+                "http://localhost:8080/songs/378195?text_format=plain",
+                "http://localhost:8081/Sia-chandelier-lyrics",
+                "http://localhost:8081/Sia-chandelier-lyrics",
+                "http://localhost:8081/Sia-chandelier-lyrics",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn get_annotated_lyrics_offline_test() {
+        let (genius, requests) = fake_genius(
+            Genius::builder(),
+            vec![
+                respond(200, SONG),
+                respond(200, LYRICS),
+                respond(200, REFERENTS),
+            ],
+        );
+        let annotated = genius.get_annotated_lyrics(378_195).await.unwrap();
+        let span = annotated.annotated_spans().next().unwrap();
+        assert_eq!(span.line, 1);
+        assert_eq!(span.text, "Party girls don't get hurt");
+        assert_eq!(span.annotation_ids, [2_643_137]);
+        assert_eq!(
+            requests.lock().unwrap()[2].url,
+            "https://api.genius.com/referents?song_id=378195&text_format=plain&page=1"
+        );
+    }
+}
+
+/// Extract the lyrics of a genius.com song page, one item per line.
+///
+/// The lyrics are the text of the `data-lyrics-container` blocks, `<br>` starts a new line and the formatting like
+/// italics or bold is dropped. Sections are separated by an empty line placed before their `[Header]`.
+///
+/// # Errors
+///
+/// Will return [`GeniusError::LyricsParseError`] if the page has no lyrics block or the blocks are empty.
+pub fn parse_page(html: &str) -> Result<Vec<String>, GeniusError> {
+    let document = Html::parse_document(html);
+    let containers = document
+        .root_element()
+        .descendants()
+        .filter_map(ElementRef::wrap)
+        .filter(|element| element.value().attr("data-lyrics-container") == Some("true"))
+        .collect::<Vec<_>>();
+    if containers.is_empty() {
+        return Err(GeniusError::LyricsParseError(
+            "No data-lyrics-container in the page".to_owned(),
+        ));
+    }
+    let mut lines = Lines::default();
+    for container in containers {
+        for child in container.children() {
+            lines.push_node(child);
+        }
+        lines.break_line();
+    }
+    let lines = lines.finish();
+    if lines.is_empty() {
+        return Err(GeniusError::LyricsParseError(
+            "The lyrics blocks are empty".to_owned(),
+        ));
+    }
+    Ok(lines)
+}
+
+/// Lines of the lyrics blocks being read.
+#[derive(Default)]
+struct Lines {
+    lines: Vec<String>,
+    current: String,
+}
+
+impl Lines {
+    fn push_node(&mut self, node: NodeRef<'_, Node>) {
+        match node.value() {
+            Node::Text(text) => self.current.push_str(&text.replace('\n', " ")),
+            Node::Element(element) => match element.name() {
+                "br" => self.break_line(),
+                "script" | "style" => {}
+                _ if element.attr("data-exclude-from-selection") == Some("true") => {}
+                _ => {
+                    for child in node.children() {
+                        self.push_node(child);
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+
+    fn break_line(&mut self) {
+        let line = std::mem::take(&mut self.current);
+        self.lines.push(line.trim().to_owned());
+    }
+
+    /// Trim the empty lines and keep one before each `[Header]`.
+    fn finish(self) -> Vec<String> {
+        let mut lines: Vec<String> = Vec::with_capacity(self.lines.len());
+        for line in self.lines {
+            if line.is_empty() || is_header(&line) {
+                while lines.last().is_some_and(String::is_empty) {
+                    lines.pop();
+                }
+                if !lines.is_empty() {
+                    lines.push(String::new());
+                }
+            }
+            if !line.is_empty() {
+                lines.push(line);
+            }
+        }
+        while lines.last().is_some_and(String::is_empty) {
+            lines.pop();
+        }
+        lines
+    }
+}
+
+/// Split the plain lyrics of [`Song::lyrics`] in lines like [`parse_page`] does.
+#[must_use]
+pub fn parse_plain(text: &str) -> Vec<String> {
+    let lines = Lines {
+        lines: text.lines().map(|line| line.trim().to_owned()).collect(),
+        current: String::new(),
+    };
+    lines.finish()
+}
+
+/// Where [`crate::Genius::get_lyrics`] looks for the lyrics, set with [`crate::GeniusBuilder::lyrics_strategy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LyricsStrategy {
+    /// Use [`Song::lyrics`] when the API gives it, which needs a `user-core` token, otherwise read the song page.
+    #[default]
+    PreferApi,
+    /// Only use [`Song::lyrics`].
+    Api,
+    /// Always read the song page.
+    Page,
+}
+
+/// Where the [`Lyrics`] come from.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LyricsSource {
+    /// [`Song::lyrics`] of the API.
+    Api,
+    /// The `data-lyrics-container` blocks of the song page.
+    Page,
+}
+
+/// Lyrics of `song` from the API if `strategy` allows it, `None` when the song page must be read.
+pub(crate) fn api_lyrics(
+    song: &Song,
+    strategy: LyricsStrategy,
+) -> Result<Option<Lyrics>, GeniusError> {
+    if strategy == LyricsStrategy::Page {
+        return Ok(None);
+    }
+    match song
+        .lyrics
+        .as_ref()
+        .and_then(|lyrics| lyrics.plain.as_deref())
+    {
+        Some(plain) => Ok(Some(Lyrics::new(
+            parse_plain(plain),
+            song,
+            LyricsSource::Api,
+        ))),
+        None if strategy == LyricsStrategy::Api => Err(GeniusError::LyricsUnavailable(song.id)),
+        None => Ok(None),
+    }
+}
+
+/// Whether `line` is a section header like `[Chorus]` or `[Verse 1: Sia]`.
+pub(crate) fn is_header(line: &str) -> bool {
+    line.starts_with('[') && line.ends_with(']')
+}
+
+/// Lyrics of a song split in sections, returned by [`crate::Genius::get_lyrics`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Lyrics {
+    pub sections: Vec<Section>,
+    /// Where the lyrics were read.
+    pub source: LyricsSource,
+}
+
+/// Part of the lyrics under a header like `[Verse 2: Ariana Grande & The Weeknd]`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// Kind of the section, the lines before the first header are in a section of kind `Other("")`.
+    pub kind: SectionKind,
+    /// Header as it is written in the lyrics, without the brackets.
+    pub header: Option<String>,
+    /// Number of the section, `2` for `[Verse 2]`.
+    pub ordinal: Option<u32>,
+    /// Artists named after the `:` of the header, empty when the header doesn't name them.
+    pub performers: Vec<Performer>,
+    pub lines: Vec<String>,
+}
+
+/// Kind of a [`Section`] read from its header.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum SectionKind {
+    Intro,
+    Verse,
+    PreChorus,
+    Chorus,
+    Bridge,
+    Outro,
+    /// Any other header like `Refrain` or `Post-Chorus`.
+    Other(String),
+}
+
+/// Artist performing a [`Section`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Performer {
+    /// Name as it is written in the header.
+    pub name: String,
+    /// Id of the primary or featured artist of the song with this name.
+    pub artist_id: Option<u32>,
+}
+
+impl Lyrics {
+    /// Split the `lines` of [`parse_page`] or [`parse_plain`] in sections, the performers are resolved against the
+    /// primary and featured artists of `song`.
+    #[must_use]
+    pub fn new(lines: Vec<String>, song: &Song, source: LyricsSource) -> Self {
+        let artists = std::iter::once(&song.primary_artist)
+            .chain(song.featured_artists.iter().flatten())
+            .collect::<Vec<_>>();
+        let mut sections: Vec<Section> = Vec::new();
+        for line in lines {
+            if is_header(&line) {
+                sections.push(Section::from_header(&line[1..line.len() - 1], &artists));
+                continue;
+            }
+            if sections.is_empty() {
+                if line.is_empty() {
+                    continue;
+                }
+                sections.push(Section {
+                    kind: SectionKind::Other(String::new()),
+                    header: None,
+                    ordinal: None,
+                    performers: Vec::new(),
+                    lines: Vec::new(),
+                });
+            }
+            if let Some(section) = sections.last_mut() {
+                section.lines.push(line);
+            }
+        }
+        for section in &mut sections {
+            while section.lines.last().is_some_and(String::is_empty) {
+                section.lines.pop();
+            }
+        }
+        Self { sections, source }
+    }
+
+    /// Flat view of the lyrics, one item per line with the headers and an empty line between the sections.
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        for section in &self.sections {
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            if let Some(header) = &section.header {
+                lines.push(format!("[{header}]"));
+            }
+            lines.extend(section.lines.iter().cloned());
+        }
+        lines
+    }
+}
+
+impl Section {
+    fn from_header(header: &str, artists: &[&Artist]) -> Self {
+        let (name, performers) = match header.split_once(':') {
+            Some((name, performers)) => (name.trim(), performers),
+            None => (header.trim(), ""),
+        };
+        let (kind, ordinal) = section_kind(name);
+        Self {
+            kind,
+            header: Some(header.to_owned()),
+            ordinal,
+            performers: performers_of(performers, artists),
+            lines: Vec::new(),
+        }
+    }
+}
+
+/// Performers named in `text` like `Simon & Garfunkel, Sia`. The names of `artists` are matched first so a name
+/// containing `&` or `,` is kept whole, the rest is split on `&` and `,`.
+fn performers_of(text: &str, artists: &[&Artist]) -> Vec<Performer> {
+    let is_separator = |c: char| c == ',' || c == '&' || c.is_whitespace();
+    let mut performers = Vec::new();
+    let mut rest = text.trim_start_matches(is_separator);
+    while !rest.is_empty() {
+        let artist = artists
+            .iter()
+            .filter(|artist| {
+                let len = artist.name.len();
+                len > 0
+                    && rest
+                        .get(..len)
+                        .is_some_and(|name| name.eq_ignore_ascii_case(&artist.name))
+                    && rest[len..]
+                        .trim_start()
+                        .chars()
+                        .next()
+                        .is_none_or(|c| c == ',' || c == '&')
+            })
+            .max_by_key(|artist| artist.name.len());
+        let len = artist.map_or_else(
+            || rest.find([',', '&']).unwrap_or(rest.len()),
+            |artist| artist.name.len(),
+        );
+        let name = rest[..len].trim();
+        performers.push(Performer {
+            name: name.to_owned(),
+            artist_id: artist
+                .or_else(|| {
+                    artists
+                        .iter()
+                        .find(|artist| artist.name.eq_ignore_ascii_case(name))
+                })
+                .map(|artist| artist.id),
+        });
+        rest = rest[len..].trim_start_matches(is_separator);
+    }
+    performers
+}
+
+/// Kind and ordinal of a header name like `Verse 2`, words after the ordinal like in `Chorus x2` are ignored.
+fn section_kind(name: &str) -> (SectionKind, Option<u32>) {
+    let mut words = name.split_whitespace();
+    let kind = words.next().unwrap_or_default();
+    let ordinal = words.next().and_then(|word| word.parse().ok());
+    let kind = match kind.to_lowercase().as_str() {
+        "intro" => SectionKind::Intro,
+        "verse" => SectionKind::Verse,
+        "pre-chorus" | "prechorus" => SectionKind::PreChorus,
+        "chorus" => SectionKind::Chorus,
+        "bridge" => SectionKind::Bridge,
+        "outro" => SectionKind::Outro,
+        _ => SectionKind::Other(name.to_owned()),
+    };
+    (kind, ordinal)
+}
+
+/// Lyrics joined with the referents of the song, created with [`Genius::get_annotated_lyrics`] or
+/// [`AnnotatedLyrics::new`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedLyrics {
+    pub lyrics: Lyrics,
+    /// Lines of [`Lyrics::lines`] split in spans by the referents covering them.
+    pub lines: Vec<AnnotatedLine>,
+}
+
+/// Line of [`AnnotatedLyrics`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AnnotatedLine {
+    pub text: String,
+    /// Consecutive parts of the text with the same referents, an empty line has no span.
+    pub spans: Vec<Span>,
+}
+
+/// Part of an [`AnnotatedLine`], `start` and `end` are byte offsets in its text.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    /// Referents whose fragment covers this span, empty when it is not annotated.
+    pub referent_ids: Vec<u32>,
+    /// Annotations of these referents.
+    pub annotation_ids: Vec<u32>,
+}
+
+/// Annotated span returned by [`AnnotatedLyrics::annotated_spans`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnnotatedSpan<'a> {
+    /// Index of the line in [`AnnotatedLyrics::lines`].
+    pub line: usize,
+    pub text: &'a str,
+    pub referent_ids: &'a [u32],
+    pub annotation_ids: &'a [u32],
+}
+
+/// Part of the lyrics text matching the fragment of a referent.
+struct Match<'a> {
+    start: usize,
+    end: usize,
+    referent: &'a Referent,
+}
+
+impl AnnotatedLyrics {
+    /// Match the fragment of each referent against the lyrics text, a fragment found several times like in a
+    /// repeated chorus covers every occurrence and a fragment not found is ignored.
+    #[must_use]
+    pub fn new(lyrics: Lyrics, referents: &[Referent]) -> Self {
+        let lines = lyrics.lines();
+        let text = lines.join("\n");
+        let mut matches = Vec::new();
+        for referent in referents {
+            let fragment = referent
+                .range
+                .get("content")
+                .unwrap_or(&referent.fragment)
+                .trim();
+            if fragment.is_empty() {
+                continue;
+            }
+            for (start, found) in text.match_indices(fragment) {
+                matches.push(Match {
+                    start,
+                    end: start + found.len(),
+                    referent,
+                });
+            }
+        }
+        let mut offset = 0;
+        let lines = lines
+            .into_iter()
+            .map(|text| {
+                let line = AnnotatedLine::new(text, offset, &matches);
+                offset += line.text.len() + 1;
+                line
+            })
+            .collect();
+        Self { lyrics, lines }
+    }
+
+    /// Spans covered by at least one referent, in the order of the lyrics.
+    pub fn annotated_spans(&self) -> impl Iterator<Item = AnnotatedSpan<'_>> {
+        self.lines.iter().enumerate().flat_map(|(index, line)| {
+            line.spans
+                .iter()
+                .filter(|span| !span.referent_ids.is_empty())
+                .map(move |span| AnnotatedSpan {
+                    line: index,
+                    text: &line.text[span.start..span.end],
+                    referent_ids: &span.referent_ids,
+                    annotation_ids: &span.annotation_ids,
+                })
+        })
+    }
+}
+
+impl AnnotatedLine {
+    /// Split `text`, starting at `offset` in the lyrics text, at the bounds of the `matches`.
+    fn new(text: String, offset: usize, matches: &[Match<'_>]) -> Self {
+        let end = offset + text.len();
+        let mut cuts = vec![0, text.len()];
+        for m in matches {
+            let (start, end) = (m.start.max(offset), m.end.min(end));
+            if start < end {
+                cuts.extend([start - offset, end - offset]);
+            }
+        }
+        cuts.sort_unstable();
+        cuts.dedup();
+        let spans = cuts
+            .windows(2)
+            .map(|cut| {
+                let covering = matches
+                    .iter()
+                    .filter(|m| m.start <= offset + cut[0] && offset + cut[1] <= m.end)
+                    .map(|m| m.referent)
+                    .collect::<Vec<_>>();
+                let mut referent_ids = covering.iter().map(|r| r.id).collect::<Vec<_>>();
+                let mut annotation_ids = covering
+                    .iter()
+                    .flat_map(|r| r.annotations.iter().map(|a| a.id))
+                    .collect::<Vec<_>>();
+                for ids in [&mut referent_ids, &mut annotation_ids] {
+                    ids.sort_unstable();
+                    ids.dedup();
+                }
+                Span {
+                    start: cut[0],
+                    end: cut[1],
+                    referent_ids,
+                    annotation_ids,
+                }
+            })
+            .collect();
+        Self { text, spans }
+    }
+
+    /// Annotations covering any part of the line.
+    #[must_use]
+    pub fn annotation_ids(&self) -> Vec<u32> {
+        let mut ids = self
+            .spans
+            .iter()
+            .flat_map(|span| span.annotation_ids.iter().copied())
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+}
+
+impl Genius {
+    /// Get the lyrics of a song by it's id split in sections, see [`Lyrics`]. By default they are the
+    /// [`Song::lyrics`] of the API when the token allows it, otherwise they are read from the song page like
+    /// <https://genius.com/Sia-chandelier-lyrics> on [`crate::GeniusBuilder::web_url`], see
+    /// [`crate::GeniusBuilder::lyrics_strategy`] and [`Lyrics::source`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::get_song`] for the song request.
+    /// Will return [`GeniusError::RequestError`] if the request of the page fails, the status errors are mapped as described in [`GeniusError`].
+    /// Will return [`GeniusError::LyricsParseError`] if the page doesn't have the lyrics blocks, if this occurs you should contact the developer.
+    /// Will return [`GeniusError::LyricsUnavailable`] if the strategy is [`LyricsStrategy::Api`] and the song
+    /// doesn't have lyrics.
+    pub async fn get_lyrics(&self, id: u32) -> Result<Lyrics, GeniusError> {
+        let song = self.get_song(id, TextFormat::PLAIN).await?;
+        if let Some(lyrics) = api_lyrics(&song, self.inner.config.lyrics_strategy)? {
+            return Ok(lyrics);
+        }
+        let url = links::on_web_url(&song.url, &self.inner.config.web_url);
+        let lines = parse_page(&self.get_page(&url).await?)?;
+        Ok(Lyrics::new(lines, &song, LyricsSource::Page))
+    }
+
+    /// Get the lyrics of a song by it's id joined with all its referents, see [`AnnotatedLyrics`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Genius::get_lyrics`] and [`crate::annotation::Referents::collect_all`].
+    pub async fn get_annotated_lyrics(&self, id: u32) -> Result<AnnotatedLyrics, GeniusError> {
+        let lyrics = self.get_lyrics(id).await?;
+        let referents = self
+            .referents()
+            .song_id(id)
+            .text_format(TextFormat::PLAIN)
+            .collect_all()
+            .await?;
+        Ok(AnnotatedLyrics::new(lyrics, &referents))
+    }
+}
@@ -0,0 +1,116 @@
+use futures_core::Stream;
+use futures_util::stream;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::error::GeniusError;
+
+#[cfg(test)]
+mod test {
+    use crate::error::GeniusError;
+    use crate::page::{clamp_per_page, paginate, Page};
+    use futures_util::{future, StreamExt};
+
+    #[tokio::test]
+    async fn paginate_test() {
+        let pages = |page: u32| {
+            future::ready(match page {
+                1 => Ok(Page {
+                    items: vec![1, 2],
+                    next_page: Some(2),
+                }),
+                2 => Ok(Page {
+                    items: vec![3, 4],
+                    next_page: Some(3),
+                }),
+                _ => Err(GeniusError::ParseError("page 3".to_owned())),
+            })
+        };
+        let items = paginate(1, None, pages).collect::<Vec<_>>().await;
+        assert_eq!(items.len(), 5);
+        assert!(matches!(items[4], Err(GeniusError::ParseError(_))));
+
+        let items = paginate(2, Some(1), pages).collect::<Vec<_>>().await;
+        assert!(matches!(items[..], [Ok(3)]));
+    }
+
+    #[test]
+    fn clamp_per_page_test() {
+        assert_eq!(clamp_per_page(0, 20), 1);
+        assert_eq!(clamp_per_page(5, 20), 5);
+        assert_eq!(clamp_per_page(50, 20), 20);
+    }
+}
+
+/// Stream of the items of a paginated endpoint, the next page is requested when the items of the previous one have
+/// been consumed.
+///
+/// It ends after the last page, after the item limit or after the first error.
+pub type PageStream<T> = Pin<Box<dyn Stream<Item = Result<T, GeniusError>> + Send>>;
+
+/// Items of one page with the number of the next one.
+#[derive(Debug)]
+pub(crate) struct Page<T> {
+    pub(crate) items: Vec<T>,
+    pub(crate) next_page: Option<u32>,
+}
+
+/// `per_page` clamped to `1..=max`, a page size outside of it would make the full page check loop or stop early.
+pub(crate) const fn clamp_per_page(per_page: u32, max: u32) -> u32 {
+    if per_page == 0 {
+        1
+    } else if per_page > max {
+        max
+    } else {
+        per_page
+    }
+}
+
+/// `path` with the `query` parameters and the `page` parameter.
+pub(crate) fn page_path(path: &str, query: &[String], page: u32) -> String {
+    let mut query = query.to_vec();
+    query.push(format!("page={page}"));
+    format!("{path}?{}", query.join("&"))
+}
+
+struct State<T, F> {
+    fetch: F,
+    next_page: Option<u32>,
+    items: VecDeque<T>,
+    remaining: Option<usize>,
+}
+
+/// Stream the items of the pages returned by `fetch`, starting at `first_page` and stopping after `limit` items.
+pub(crate) fn paginate<T, F, Fut>(first_page: u32, limit: Option<usize>, fetch: F) -> PageStream<T>
+where
+    T: Send + 'static,
+    F: FnMut(u32) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<Page<T>, GeniusError>> + Send,
+{
+    let state = State {
+        fetch,
+        next_page: Some(first_page),
+        items: VecDeque::new(),
+        remaining: limit,
+    };
+    Box::pin(stream::unfold(state, |mut state| async move {
+        loop {
+            if state.remaining == Some(0) {
+                return None;
+            }
+            if let Some(item) = state.items.pop_front() {
+                state.remaining = state.remaining.map(|remaining| remaining - 1);
+                return Some((Ok(item), state));
+            }
+            let page = state.next_page.take()?;
+            match (state.fetch)(page).await {
+                Ok(page) => {
+                    state.items.extend(page.items);
+                    state.next_page = page.next_page;
+                }
+                Err(e) => return Some((Err(e), state)),
+            }
+        }
+    }))
+}
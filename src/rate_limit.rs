@@ -0,0 +1,126 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod test {
+    use crate::rate_limit::{RateLimit, TokenBucket};
+    use std::time::Duration;
+
+    #[test]
+    fn reserve_test() {
+        let bucket = TokenBucket::new(&RateLimit::per_second(2).burst(3));
+        for _ in 0..3 {
+            assert_eq!(bucket.reserve(), Duration::ZERO);
+        }
+        let wait = bucket.reserve();
+        assert!(wait > Duration::from_millis(400) && wait <= Duration::from_millis(500));
+        let wait = bucket.reserve();
+        assert!(wait > Duration::from_millis(900) && wait <= Duration::from_secs(1));
+    }
+}
+
+/// Maximum request rate of a client, configured with [`crate::GeniusBuilder::rate_limit`].
+///
+/// Requests take a token from a bucket refilled at `requests` per `period` that holds at most `burst` tokens,
+/// when it is empty the request waits for the next token. Clones of a [`crate::Genius`] share the same bucket.
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::{rate_limit::RateLimit, Genius};
+///
+/// let genius = Genius::builder()
+///     .token("my_token")
+///     .rate_limit(RateLimit::per_second(5).burst(10))
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimit {
+    requests: u32,
+    period: Duration,
+    burst: u32,
+}
+
+impl RateLimit {
+    /// `requests` per `period` with a burst of `requests`.
+    #[must_use]
+    pub fn new(requests: u32, period: Duration) -> Self {
+        let requests = requests.max(1);
+        Self {
+            requests,
+            period,
+            burst: requests,
+        }
+    }
+
+    /// `requests` per second with a burst of `requests`.
+    #[must_use]
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    /// Number of requests that can be sent at once after the client was idle.
+    #[must_use]
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    fn tokens_per_second(&self) -> f64 {
+        f64::from(self.requests) / self.period.as_secs_f64()
+    }
+}
+
+/// Shared state of a [`RateLimit`].
+#[derive(Debug)]
+pub(crate) struct TokenBucket {
+    rate: f64,
+    capacity: f64,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    pub(crate) fn new(limit: &RateLimit) -> Self {
+        let capacity = f64::from(limit.burst);
+        Self {
+            rate: limit.tokens_per_second(),
+            capacity,
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                updated_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Take a token and return how long to wait before using it, tokens taken while the bucket is empty are
+    /// queued so concurrent callers are spread over time.
+    pub(crate) fn reserve(&self) -> Duration {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.updated_at).as_secs_f64();
+        state.tokens = elapsed.mul_add(self.rate, state.tokens).min(self.capacity);
+        state.updated_at = now;
+        state.tokens -= 1.0;
+        if state.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-state.tokens / self.rate)
+        }
+    }
+
+    /// Wait until a token is available.
+    pub(crate) async fn acquire(&self) {
+        let wait = self.reserve();
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
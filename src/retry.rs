@@ -0,0 +1,179 @@
+use std::time::{Duration, SystemTime};
+
+#[cfg(test)]
+mod test {
+    use crate::retry::{parse_retry_after, RetryPolicy};
+    use std::time::Duration;
+
+    #[test]
+    fn delay_test() {
+        let policy = RetryPolicy::default()
+            .max_attempts(4)
+            .base_delay(Duration::from_millis(100))
+            .jitter(false);
+        assert_eq!(policy.delay(1, None), Some(Duration::from_millis(100)));
+        assert_eq!(policy.delay(2, None), Some(Duration::from_millis(200)));
+        assert_eq!(policy.delay(3, None), Some(Duration::from_millis(400)));
+        assert_eq!(policy.delay(4, None), None);
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(2))),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(policy.delay(1, Some(Duration::from_secs(45))), None);
+        assert_eq!(RetryPolicy::none().delay(1, None), None);
+    }
+
+    #[test]
+    fn parse_retry_after_test() {
+        assert_eq!(parse_retry_after("3"), Some(Duration::from_secs(3)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+}
+
+/// When and how often a failed request is retried, configured with [`crate::GeniusBuilder::retry_policy`].
+///
+/// The delay before the attempt `n + 1` is `base_delay * 2^(n - 1)` capped at `max_delay`, with a random jitter
+/// of up to half of it. When the response has a `Retry-After` header that delay is used instead, a `Retry-After`
+/// longer than `max_delay` stops the retries.
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::{retry::RetryPolicy, Genius};
+/// use std::time::Duration;
+///
+/// let genius = Genius::builder()
+///     .token("my_token")
+///     .retry_policy(RetryPolicy::default().max_attempts(5).base_delay(Duration::from_secs(1)))
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    statuses: Vec<u16>,
+    transport_errors: bool,
+    respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    /// 3 attempts starting at 500ms, retrying `429`, `500`, `502`, `503`, `504`, timeouts and connection errors.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            statuses: vec![429, 500, 502, 503, 504],
+            transport_errors: true,
+            respect_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry, this is the policy of a client built without [`crate::GeniusBuilder::retry_policy`].
+    #[must_use]
+    pub fn none() -> Self {
+        Self::default().max_attempts(1)
+    }
+
+    /// Total number of attempts including the first one.
+    #[must_use]
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Delay before the first retry.
+    #[must_use]
+    pub const fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Upper bound of the delay between two attempts.
+    #[must_use]
+    pub const fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// If a random jitter is added to the delay so concurrent clients don't retry together.
+    #[must_use]
+    pub const fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Response statuses that are retried.
+    #[must_use]
+    pub fn statuses(mut self, statuses: impl Into<Vec<u16>>) -> Self {
+        self.statuses = statuses.into();
+        self
+    }
+
+    /// If timeouts and connection errors are retried.
+    #[must_use]
+    pub const fn transport_errors(mut self, transport_errors: bool) -> Self {
+        self.transport_errors = transport_errors;
+        self
+    }
+
+    /// If the `Retry-After` header of the response is used as delay.
+    #[must_use]
+    pub const fn respect_retry_after(mut self, respect_retry_after: bool) -> Self {
+        self.respect_retry_after = respect_retry_after;
+        self
+    }
+
+    /// If a response with this status is retried.
+    #[must_use]
+    pub fn retries_status(&self, status: u16) -> bool {
+        self.statuses.contains(&status)
+    }
+
+    /// If a timeout or connection error is retried.
+    #[must_use]
+    pub const fn retries_transport_errors(&self) -> bool {
+        self.transport_errors
+    }
+
+    /// Delay to wait after the failed attempt number `attempt`, `None` if there is no retry left.
+    #[must_use]
+    pub fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        if let Some(retry_after) = retry_after.filter(|_| self.respect_retry_after) {
+            return (retry_after <= self.max_delay).then_some(retry_after);
+        }
+        let delay = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(attempt - 1))
+            .min(self.max_delay);
+        if self.jitter {
+            Some(delay / 2 + delay.mul_f64(fastrand::f64() / 2.0))
+        } else {
+            Some(delay)
+        }
+    }
+}
+
+/// Parse a `Retry-After` header, either a number of seconds or an HTTP date.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
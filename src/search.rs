@@ -1,11 +1,354 @@
+use futures_util::TryStreamExt;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::album::Album;
+use crate::artist::Artist;
+use crate::cache::CacheKey;
+use crate::client;
+use crate::error::GeniusError;
+use crate::page::{self, Page, PageStream};
 use crate::song::Song;
+use crate::user::User;
+use crate::Genius;
+
+#[cfg(test)]
+mod test {
+    use crate::search::{HitType, TopHit};
+    use crate::tests::{fake_genius, respond, urls, SEARCH, SEARCH_MULTI};
+    use crate::Genius;
+    use reqwest::header::AUTHORIZATION;
+
+    #[tokio::test]
+    async fn search_offline_test() {
+        let (genius, requests) = fake_genius(Genius::builder(), vec![respond(200, SEARCH)]);
+        let hits = genius.search("Sia").await.unwrap();
+        assert_eq!(hits[0].result.full_title, "Chandelier by Sia");
+        assert_eq!(hits[0].hit_type, HitType::Song);
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(requests[0].url, "https://api.genius.com/search?q=Sia");
+        assert_eq!(requests[0].headers[AUTHORIZATION], "Bearer token");
+    }
 
-use serde::{Serialize, Deserialize};
+    #[tokio::test]
+    async fn search_query_offline_test() {
+        let (genius, requests) = fake_genius(
+            Genius::builder(),
+            vec![respond(200, SEARCH), respond(200, SEARCH)],
+        );
+        assert_eq!(genius.search("Sía & AC/DC #1+").await.unwrap().len(), 1);
+        let hits = genius
+            .search_query("Sia")
+            .per_page(1)
+            .limit(1)
+            .collect_all()
+            .await
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        let urls = urls(&requests);
+        assert_eq!(
+            urls,
+            [
+                "https://api.genius.com/search?q=S%C3%ADa+%26+AC%2FDC+%231%2B",
+                "https://api.genius.com/search?q=Sia&per_page=1&page=1",
+            ]
+        );
+
+        let (genius, requests) = fake_genius(
+            Genius::builder(),
+            vec![respond(200, SEARCH), respond(200, SEARCH)],
+        );
+        let hits = genius
+            .search_query("Sia")
+            .per_page(1)
+            .page(3)
+            .limit(2)
+            .collect_all()
+            .await
+            .unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(
+            requests.lock().unwrap()[1].url,
+            "https://api.genius.com/search?q=Sia&per_page=1&page=4"
+        );
+    }
+
+    #[tokio::test]
+    async fn search_multi_offline_test() {
+        let (genius, requests) = fake_genius(Genius::builder(), vec![respond(200, SEARCH_MULTI)]);
+        let search = genius.search_multi("Sia").await.unwrap();
+        assert!(matches!(search.top_hit, Some(TopHit::Artist(artist)) if artist.name == "Sia"));
+        assert_eq!(search.songs[0].full_title, "Chandelier by Sia");
+        assert_eq!(search.lyrics.len(), 1);
+        assert_eq!(search.artists[0].id, 16_775);
+        assert_eq!(search.albums[0].name, "1000 Forms of Fear");
+        assert_eq!(search.videos.len(), 1);
+        assert_eq!(search.articles[0]["id"], 5678);
+        assert_eq!(search.users[0].login, "listener");
+        assert_eq!(
+            requests.lock().unwrap()[0].url,
+            "https://api.genius.com/search/multi?q=Sia"
+        );
+    }
+}
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Hit {
     pub index: String,
     #[serde(rename = "type")]
-    pub hit_type: String,
+    pub hit_type: HitType,
     pub result: Song,
 }
+
+/// Type of the result of a hit.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HitType {
+    Song,
+    Artist,
+    Album,
+    Video,
+    Article,
+    User,
+    /// Any type not known by this crate.
+    #[serde(other)]
+    Other,
+}
+
+/// Results of [`Genius::search_multi`] grouped by section.
+#[derive(Serialize, Debug)]
+pub struct MultiSearch {
+    /// Best result of all the sections.
+    pub top_hit: Option<TopHit>,
+    pub songs: Vec<Song>,
+    /// Songs whose lyrics match the query.
+    pub lyrics: Vec<Song>,
+    pub artists: Vec<Artist>,
+    pub albums: Vec<Album>,
+    /// Videos as they are returned by Genius.
+    pub videos: Vec<Value>,
+    /// Articles as they are returned by Genius.
+    pub articles: Vec<Value>,
+    pub users: Vec<User>,
+}
+
+/// Result of the top hit section of [`MultiSearch`].
+#[derive(Serialize, Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum TopHit {
+    Song(Song),
+    Artist(Artist),
+    Album(Album),
+    User(User),
+    /// A video, an article or any other type, as it is returned by Genius.
+    Other(HitType, Value),
+}
+
+/// Section of the `search/multi` response.
+#[derive(Serialize, Deserialize, Debug)]
+pub(crate) struct Section {
+    #[serde(rename = "type")]
+    section_type: String,
+    hits: Vec<SectionHit>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct SectionHit {
+    #[serde(rename = "type")]
+    hit_type: HitType,
+    result: Value,
+}
+
+impl MultiSearch {
+    /// Sort the results of the `sections` by type, the unknown sections are ignored.
+    pub(crate) fn from_sections(sections: Vec<Section>) -> Result<Self, GeniusError> {
+        let mut search = Self {
+            top_hit: None,
+            songs: Vec::new(),
+            lyrics: Vec::new(),
+            artists: Vec::new(),
+            albums: Vec::new(),
+            videos: Vec::new(),
+            articles: Vec::new(),
+            users: Vec::new(),
+        };
+        for section in sections {
+            match section.section_type.as_str() {
+                "top_hit" => {
+                    search.top_hit = section.hits.into_iter().next().map(top_hit).transpose()?;
+                }
+                "song" => search.songs = results(section.hits)?,
+                "lyric" => search.lyrics = results(section.hits)?,
+                "artist" => search.artists = results(section.hits)?,
+                "album" => search.albums = results(section.hits)?,
+                "video" => search.videos = results(section.hits)?,
+                "article" => search.articles = results(section.hits)?,
+                "user" => search.users = results(section.hits)?,
+                _ => {}
+            }
+        }
+        Ok(search)
+    }
+}
+
+fn top_hit(hit: SectionHit) -> Result<TopHit, GeniusError> {
+    Ok(match hit.hit_type {
+        HitType::Song => TopHit::Song(result(hit.result)?),
+        HitType::Artist => TopHit::Artist(result(hit.result)?),
+        HitType::Album => TopHit::Album(result(hit.result)?),
+        HitType::User => TopHit::User(result(hit.result)?),
+        hit_type => TopHit::Other(hit_type, hit.result),
+    })
+}
+
+fn results<T: DeserializeOwned>(hits: Vec<SectionHit>) -> Result<Vec<T>, GeniusError> {
+    hits.into_iter().map(|hit| result(hit.result)).collect()
+}
+
+fn result<T: DeserializeOwned>(result: Value) -> Result<T, GeniusError> {
+    serde_json::from_value(result).map_err(|e| GeniusError::ParseError(e.to_string()))
+}
+
+/// Search request with its pages, created with [`Genius::search_query`].
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::Genius;
+///
+/// # async fn run() -> Result<(), genius_rust::error::GeniusError> {
+/// let genius = Genius::new(dotenv::var("TOKEN").unwrap());
+/// let hits = genius
+///     .search_query("AC/DC & friends")
+///     .per_page(20)
+///     .limit(50)
+///     .collect_all()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct SearchQuery {
+    genius: Genius,
+    q: String,
+    per_page: Option<u32>,
+    page: u32,
+    limit: Option<usize>,
+}
+
+impl SearchQuery {
+    pub(crate) fn new(genius: Genius, q: &str) -> Self {
+        Self {
+            genius,
+            q: q.to_owned(),
+            per_page: None,
+            page: 1,
+            limit: None,
+        }
+    }
+
+    /// Number of hits requested per page, Genius defaults to 10 and allows at most 20, `per_page` is clamped to
+    /// `1..=20`.
+    #[must_use]
+    pub const fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = Some(page::clamp_per_page(per_page, 20));
+        self
+    }
+
+    /// First page requested, defaults to 1.
+    #[must_use]
+    pub const fn page(mut self, page: u32) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Stop after `limit` hits, by default all the pages are requested.
+    #[must_use]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Request only the first page.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Genius::search`].
+    pub async fn send(self) -> Result<Vec<Hit>, GeniusError> {
+        let path = page::page_path("/search", &self.query(), self.page);
+        self.genius.get(&path).await?.hits()
+    }
+
+    /// Stream of the hits, the pages are requested until one is not full.
+    ///
+    /// A failed request is returned as the last item, see [`Genius::search`] for the errors.
+    #[must_use]
+    pub fn stream(self) -> PageStream<Hit> {
+        let query = self.query();
+        let per_page = self.per_page.unwrap_or(10) as usize;
+        let genius = self.genius;
+        page::paginate(self.page, self.limit, move |page| {
+            let genius = genius.clone();
+            let path = page::page_path("/search", &query, page);
+            async move {
+                let hits = genius.get(&path).await?.hits()?;
+                let next_page = (hits.len() >= per_page).then_some(page + 1);
+                Ok(Page {
+                    items: hits,
+                    next_page,
+                })
+            }
+        })
+    }
+
+    /// Request all the pages and collect the hits.
+    ///
+    /// # Errors
+    ///
+    /// The first error of the [`Self::stream`].
+    pub async fn collect_all(self) -> Result<Vec<Hit>, GeniusError> {
+        self.stream().try_collect().await
+    }
+
+    fn query(&self) -> Vec<String> {
+        let query = [
+            Some(format!("q={}", client::encode(&self.q))),
+            self.per_page.map(|per_page| format!("per_page={per_page}")),
+        ];
+        query.iter().flatten().cloned().collect()
+    }
+}
+
+impl Genius {
+    /// Search for a song in Genius the result will be [`Hit`]
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid, the other status errors are mapped as described in [`GeniusError`].
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON or the field `hits` is missing, if this occurs you should contact the developer.
+    pub async fn search(&self, q: &str) -> Result<Vec<Hit>, GeniusError> {
+        self.get(CacheKey::search(q).as_str()).await?.hits()
+    }
+
+    /// Search for `q` in all the sections of Genius: songs, lyrics, artists, albums, videos, articles and users, see
+    /// [`MultiSearch`].
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid, the other status errors are mapped as described in [`GeniusError`].
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON, the field `sections` is missing or a
+    /// result doesn't match the type of its section, if this occurs you should contact the developer.
+    pub async fn search_multi(&self, q: &str) -> Result<MultiSearch, GeniusError> {
+        self.get(CacheKey::search_multi(q).as_str())
+            .await?
+            .multi_search()
+    }
+
+    /// Search for `q` with the number of hits per page and the pages, see [`SearchQuery`] for the stream of hits.
+    #[must_use]
+    pub fn search_query(&self, q: &str) -> SearchQuery {
+        SearchQuery::new(self.clone(), q)
+    }
+}
@@ -1,9 +1,40 @@
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
 use crate::album::Album;
 use crate::annotation::Referent;
+pub use crate::artist::Artist;
+use crate::cache::CacheKey;
+use crate::error::GeniusError;
 use crate::user::{User, UserMetadata};
-use crate::{Body, Date};
+use crate::{Body, Date, Genius, TextFormat};
+
+#[cfg(test)]
+mod test {
+    use crate::dom::DomNode;
+    use crate::tests::{fake_genius, respond, SONG};
+    use crate::{Genius, TextFormat};
+
+    #[tokio::test]
+    async fn get_song_offline_test() {
+        let (genius, requests) = fake_genius(
+            Genius::builder().api_url("http://localhost:8080"),
+            vec![respond(200, SONG)],
+        );
+        let song = genius
+            .get_song(378_195, TextFormat::PLAIN | TextFormat::DOM)
+            .await
+            .unwrap();
+        assert_eq!(song.id, 378_195);
+        assert_eq!(song.primary_artist.name, "Sia");
+        let description = song.description.unwrap();
+        assert!(description.plain.is_some());
+        assert!(matches!(description.dom, Some(DomNode::Element(root)) if root.tag == "root"));
+        assert_eq!(
+            requests.lock().unwrap()[0].url,
+            "http://localhost:8080/songs/378195?text_format=plain,dom"
+        );
+    }
+}
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Song {
@@ -299,32 +330,18 @@ pub struct SongStatus {
     pub pageviews: Option<u32>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-pub struct Artist {
-    /// Path of the API.
-    pub api_path: String,
-    /// Artist header image.
-    pub header_image_url: String,
-    /// Artist id.
-    pub id: u32,
-    /// Artist image.
-    pub image_url: String,
-    /// First letter of the artist name.
-    /// > Only with `user-core` level token
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub index_character: Option<char>,
-    /// Is this artist a meme?
-    pub is_meme_verified: bool,
-    /// If this artist is verified.
-    pub is_verified: bool,
-    /// Name of the artist.
-    pub name: String,
-    /// > Only with `user-core` level token
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub slug: Option<String>,
-    /// Url of the artist page.
-    pub url: String,
-    /// How much iq this artist has.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub iq: Option<u32>,
+impl Genius {
+    /// Get deeper information from a song by it's id, `text_format` is the format of text bodies related to the document, see [`TextFormat`].
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::NotFound`] if there is no song with this id, the other status errors are mapped as described in [`GeniusError`].
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON or the field `song` is missing, if this occurs you should contact the developer.
+    pub async fn get_song(&self, id: u32, text_format: TextFormat) -> Result<Song, GeniusError> {
+        let res = self.get(CacheKey::song(id, text_format).as_str()).await?;
+        res.song
+            .ok_or_else(|| GeniusError::ParseError("Song not found in data".to_owned()))
+    }
 }
@@ -0,0 +1,80 @@
+use std::fmt;
+use std::ops::BitOr;
+
+#[cfg(test)]
+mod test {
+    use crate::text_format::TextFormat;
+
+    #[test]
+    fn text_format_test() {
+        assert_eq!(TextFormat::PLAIN.to_string(), "plain");
+        let format = TextFormat::DOM | TextFormat::PLAIN;
+        assert_eq!(format.to_string(), "plain,dom");
+        assert!(format.contains(TextFormat::DOM));
+        assert!(!format.contains(TextFormat::HTML));
+        assert_eq!(
+            (TextFormat::PLAIN | TextFormat::HTML | TextFormat::DOM).to_string(),
+            "plain,html,dom"
+        );
+    }
+}
+
+/// Formats of the text bodies related to a document, such as [`crate::Body`].
+///
+/// Several formats can be requested at once by combining them with `|`, the [`crate::Body`] then has a field for each.
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::TextFormat;
+///
+/// let format = TextFormat::PLAIN | TextFormat::DOM;
+/// assert_eq!(format.to_string(), "plain,dom");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextFormat(u8);
+
+impl TextFormat {
+    /// Plain text, in [`crate::Body::plain`].
+    pub const PLAIN: Self = Self(1);
+    /// HTML, in [`crate::Body::html`].
+    pub const HTML: Self = Self(1 << 1);
+    /// Tree of nodes, in [`crate::Body::dom`].
+    pub const DOM: Self = Self(1 << 2);
+
+    const NAMES: [(Self, &'static str); 3] = [
+        (Self::PLAIN, "plain"),
+        (Self::HTML, "html"),
+        (Self::DOM, "dom"),
+    ];
+
+    /// Every non-empty combination of the formats.
+    pub(crate) fn combinations() -> impl Iterator<Item = Self> {
+        (1..1 << Self::NAMES.len()).map(Self)
+    }
+
+    /// If all the formats of `other` are in this one.
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for TextFormat {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// The value of the `text_format` parameter, the names of the formats separated by commas.
+impl fmt::Display for TextFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = Self::NAMES
+            .iter()
+            .filter(|(format, _)| self.contains(*format))
+            .map(|(_, name)| *name)
+            .collect::<Vec<_>>();
+        f.write_str(&names.join(","))
+    }
+}
@@ -0,0 +1,154 @@
+use reqwest::header::HeaderMap;
+use reqwest::{Client, Method, StatusCode};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// Request handed to a [`Transport`], with the URL, token, headers and body already set by the client.
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+    /// Timeout configured with [`crate::GeniusBuilder::timeout`].
+    pub timeout: Option<Duration>,
+}
+
+/// Response returned by a [`Transport`].
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Response with `status` and `body` and no headers.
+    #[must_use]
+    pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+}
+
+/// What went wrong when a [`Transport`] couldn't get a response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportErrorKind {
+    /// The request took longer than its timeout.
+    Timeout,
+    /// The connection to the server failed.
+    Connect,
+    /// Any other failure.
+    Other,
+}
+
+/// Error of a [`Transport`], timeouts and connection errors can be retried by the [`crate::retry::RetryPolicy`].
+#[derive(Debug, Clone)]
+pub struct TransportError {
+    pub kind: TransportErrorKind,
+    pub message: String,
+}
+
+impl TransportError {
+    #[must_use]
+    pub fn new(kind: TransportErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for TransportError {}
+
+/// Future returned by [`Transport::send`].
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<HttpResponse, TransportError>> + Send + 'a>>;
+
+/// How a [`crate::Genius`] client sends its requests, configured with [`crate::GeniusBuilder::transport`].
+///
+/// The default is [`ReqwestTransport`], another implementation can use a different HTTP backend or return canned
+/// responses in tests.
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::transport::{HttpRequest, HttpResponse, Transport, TransportFuture};
+/// use genius_rust::Genius;
+/// use reqwest::StatusCode;
+///
+/// struct Fake;
+///
+/// impl Transport for Fake {
+///     fn send(&self, _request: HttpRequest) -> TransportFuture<'_> {
+///         Box::pin(async { Ok(HttpResponse::new(StatusCode::OK, r#"{"response":{"hits":[]}}"#)) })
+///     }
+/// }
+///
+/// let genius = Genius::builder().transport(Fake).build();
+/// ```
+pub trait Transport: Send + Sync {
+    /// Send `request` and read the whole response.
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_>;
+}
+
+/// [`Transport`] sending the requests with a [`reqwest::Client`].
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    #[must_use]
+    pub const fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+            if let Some(timeout) = request.timeout {
+                builder = builder.timeout(timeout);
+            }
+            let response = builder.send().await.map_err(|e| transport_error(&e))?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await.map_err(|e| transport_error(&e))?;
+            Ok(HttpResponse {
+                status,
+                headers,
+                body: body.to_vec(),
+            })
+        })
+    }
+}
+
+/// Map a [`reqwest::Error`] to a [`TransportError`] of the matching kind.
+pub(crate) fn transport_error(e: &reqwest::Error) -> TransportError {
+    let kind = if e.is_timeout() {
+        TransportErrorKind::Timeout
+    } else if e.is_connect() {
+        TransportErrorKind::Connect
+    } else {
+        TransportErrorKind::Other
+    };
+    TransportError::new(kind, e.to_string())
+}
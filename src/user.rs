@@ -1,6 +1,48 @@
-use serde::{Serialize, Deserialize};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::BTreeMap as Map;
 
+use crate::auth::Scope;
+use crate::client::Config;
+use crate::error::GeniusError;
+use crate::transport::HttpRequest;
+use crate::{Body, Genius};
+
+#[cfg(test)]
+mod test {
+    use crate::auth::Scope;
+    use crate::cache::ResponseCache;
+    use crate::error::GeniusError;
+    use crate::tests::{fake_genius, respond, urls, ACCOUNT};
+    use crate::Genius;
+    use std::time::Duration;
+
+    #[tokio::test]
+    #[allow(clippy::duration_suboptimal_units)]
+    async fn account_offline_test() {
+        let (genius, requests) = fake_genius(
+            Genius::builder().cache(ResponseCache::new(10, Duration::from_secs(60))),
+            vec![respond(200, ACCOUNT), respond(200, ACCOUNT)],
+        );
+        let account = genius.account().await.unwrap();
+        assert_eq!(account.user.login, "listener");
+        assert_eq!(account.user.iq, Some(1337));
+        assert_eq!(account.email.as_deref(), Some("listener@example.com"));
+        assert_eq!(account.stats.unwrap().transcriptions_count, Some(9));
+        assert_eq!(account.unread_messages_count, Some(1));
+        genius.account().await.unwrap();
+        let urls = urls(&requests);
+        assert_eq!(urls, ["https://api.genius.com/account"; 2]);
+
+        let (genius, _) = fake_genius(Genius::builder().scopes([Scope::Vote]), vec![]);
+        assert!(matches!(
+            genius.account().await,
+            Err(GeniusError::MissingScope(Scope::Me))
+        ));
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct User {
     /// User api path
@@ -30,6 +72,64 @@ pub struct User {
     pub current_user_metadata: UserMetadata,
 }
 
+/// The user owning the token, returned by [`Genius::account`].
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CurrentUser {
+    /// Profile of the user.
+    #[serde(flatten)]
+    pub user: User,
+    /// User description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub about_me: Option<Body>,
+    /// > Only when the token has the `me` scope
+    ///
+    /// User email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Number of users following this user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub followers_count: Option<u32>,
+    /// Number of users this user follows.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub followed_users_count: Option<u32>,
+    /// All the roles of the user.
+    #[serde(default)]
+    pub roles_for_display: Vec<String>,
+    /// Contributions of the user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<UserStats>,
+    /// Unread groups inbox items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_groups_inbox_count: Option<u32>,
+    /// Unread activity inbox items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_main_activity_inbox_count: Option<u32>,
+    /// Unread messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_messages_count: Option<u32>,
+    /// Unread newsfeed inbox items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unread_newsfeed_inbox_count: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserStats {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answers_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forum_posts_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pyongs_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub questions_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transcriptions_count: Option<u32>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AvatarImage {
     /// Image url.
@@ -54,6 +154,41 @@ pub struct Interactions {
     pub cosign: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pyong: Option<bool>,
+    /// Your vote on this annotation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vote: Option<Vote>,
+    /// If you follow this artist.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub vote: Option<u32>,
+    pub following: Option<bool>,
+}
+
+/// Vote of a user on an annotation.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Vote {
+    Up,
+    Down,
+}
+
+/// `GET /account` request, it is sent without the caches so that it always checks the token.
+pub(crate) fn account_request(config: &Config) -> Result<HttpRequest, GeniusError> {
+    config.scoped_request::<Value>(Method::GET, "/account", Scope::Me, None)
+}
+
+impl Genius {
+    /// Get the user owning the token, the token must have the `me` scope. The response is never cached, which makes
+    /// it suited to check that a token is still valid.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::MissingScope`] with [`Scope::Me`] if the scopes given to
+    /// [`crate::GeniusBuilder::scopes`] don't have `me`.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::RequestError`] if the request fails, the other status errors are mapped as described
+    /// in [`GeniusError`].
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON or the field `user` is missing.
+    pub async fn account(&self) -> Result<CurrentUser, GeniusError> {
+        let request = account_request(&self.inner.config)?;
+        self.send_uncached(request, "/account").await?.user()
+    }
 }
@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+
+use crate::annotation::Referent;
+use crate::cache::CacheKey;
+use crate::error::GeniusError;
+use crate::{Genius, TextFormat};
+
+#[cfg(test)]
+mod test {
+    use crate::error::GeniusError;
+    use crate::tests::{fake_genius, respond, urls, REFERENTS, WEB_PAGE};
+    use crate::web_page::WebPageLookup;
+    use crate::{Genius, TextFormat};
+
+    #[test]
+    fn query_test() {
+        let lookup = WebPageLookup::new()
+            .raw_annotatable_url("https://example.com/posts/1?ref=home&lang=en")
+            .og_url("https://example.com/posts/1");
+        assert_eq!(
+            lookup.query(),
+            "raw_annotatable_url=https%3A%2F%2Fexample.com%2Fposts%2F1%3Fref%3Dhome%26lang%3Den&og_url=https%3A%2F%2Fexample.com%2Fposts%2F1"
+        );
+        assert!(!lookup.is_empty());
+        assert_eq!(WebPageLookup::new().query(), "");
+        assert!(WebPageLookup::new().is_empty());
+    }
+
+    #[tokio::test]
+    async fn web_page_offline_test() {
+        let (genius, requests) = fake_genius(
+            Genius::builder(),
+            vec![respond(200, WEB_PAGE), respond(200, REFERENTS)],
+        );
+        let lookup = WebPageLookup::new().canonical_url("https://example.com/chandelier");
+        let referents = genius
+            .web_page_referents(&lookup, TextFormat::PLAIN)
+            .await
+            .unwrap();
+        assert_eq!(referents[0].id, 5_925_383);
+        let urls = urls(&requests);
+        assert_eq!(
+            urls,
+            [
+                "https://api.genius.com/web_pages/lookup?canonical_url=https%3A%2F%2Fexample.com%2Fchandelier",
+                "https://api.genius.com/referents?web_page_id=10347&text_format=plain&page=1",
+            ]
+        );
+
+        let (genius, _) = fake_genius(Genius::builder(), vec![respond(200, WEB_PAGE)]);
+        let web_page = genius.lookup_web_page(&lookup).await.unwrap();
+        assert_eq!(web_page.annotation_count, 1);
+        assert_eq!(web_page.domain, "example.com");
+
+        let (genius, requests) = fake_genius(Genius::builder(), vec![]);
+        assert!(matches!(
+            genius.lookup_web_page(&WebPageLookup::new()).await,
+            Err(GeniusError::EmptyLookup)
+        ));
+        assert!(requests.lock().unwrap().is_empty());
+    }
+}
+
+/// Web page that can be annotated on Genius, returned by [`Genius::lookup_web_page`].
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WebPage {
+    pub api_path: String,
+    /// Number of annotations on the page.
+    pub annotation_count: u32,
+    pub domain: String,
+    pub id: u32,
+    /// Url of the page without its tracking parameters.
+    pub normalized_url: String,
+    /// Url to share the annotated page.
+    pub share_url: String,
+    pub title: String,
+    pub url: String,
+}
+
+/// Urls identifying a web page for [`Genius::lookup_web_page`], at least one of them must be set.
+/// #### Examples
+/// Basic usage:
+/// ```
+/// use genius_rust::{web_page::WebPageLookup, Genius};
+///
+/// # async fn run() -> Result<(), genius_rust::error::GeniusError> {
+/// let genius = Genius::new("my_token".to_owned());
+/// let lookup = WebPageLookup::new()
+///     .raw_annotatable_url("https://example.com/posts/1?ref=home")
+///     .canonical_url("https://example.com/posts/1");
+/// let web_page = genius.lookup_web_page(&lookup).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_field_names)]
+pub struct WebPageLookup {
+    raw_annotatable_url: Option<String>,
+    canonical_url: Option<String>,
+    og_url: Option<String>,
+}
+
+impl WebPageLookup {
+    /// Lookup without any url.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Url of the page as it was opened, with its parameters.
+    #[must_use]
+    pub fn raw_annotatable_url(mut self, url: impl Into<String>) -> Self {
+        self.raw_annotatable_url = Some(url.into());
+        self
+    }
+
+    /// Url of the `<link rel="canonical">` of the page.
+    #[must_use]
+    pub fn canonical_url(mut self, url: impl Into<String>) -> Self {
+        self.canonical_url = Some(url.into());
+        self
+    }
+
+    /// Url of the `<meta property="og:url">` of the page.
+    #[must_use]
+    pub fn og_url(mut self, url: impl Into<String>) -> Self {
+        self.og_url = Some(url.into());
+        self
+    }
+
+    /// If none of the urls is set.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.raw_annotatable_url.is_none() && self.canonical_url.is_none() && self.og_url.is_none()
+    }
+
+    /// Encoded query of the urls that are set.
+    pub(crate) fn query(&self) -> String {
+        let params = [
+            ("raw_annotatable_url", &self.raw_annotatable_url),
+            ("canonical_url", &self.canonical_url),
+            ("og_url", &self.og_url),
+        ];
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        for (name, value) in &params {
+            if let Some(value) = value {
+                query.append_pair(name, value);
+            }
+        }
+        query.finish()
+    }
+}
+
+/// Cache key of `lookup`, or [`GeniusError::EmptyLookup`] if it has no url.
+pub(crate) fn lookup_key(lookup: &WebPageLookup) -> Result<CacheKey, GeniusError> {
+    if lookup.is_empty() {
+        return Err(GeniusError::EmptyLookup);
+    }
+    Ok(CacheKey::web_page(lookup))
+}
+
+impl Genius {
+    /// Find the web page matching the urls of `lookup`.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`GeniusError::EmptyLookup`] if `lookup` has no url, without sending a request.
+    /// Will return [`GeniusError::RequestError`] if the request fails.
+    /// Will return [`GeniusError::Unauthorized`] if the token is invalid.
+    /// Will return [`GeniusError::NotFound`] if Genius doesn't know this page, the other status errors are mapped as
+    /// described in [`GeniusError`].
+    /// Will return [`GeniusError::ParseError`] if the response is not valid JSON or the field `web_page` is missing.
+    pub async fn lookup_web_page(&self, lookup: &WebPageLookup) -> Result<WebPage, GeniusError> {
+        self.get(lookup_key(lookup)?.as_str()).await?.web_page()
+    }
+
+    /// Find the web page matching the urls of `lookup` and get all its referents with their annotations,
+    /// `text_format` is the format of the annotation bodies. Use [`Genius::referents`] with the id of the page to
+    /// stream them instead.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Genius::lookup_web_page`] and [`crate::annotation::Referents::collect_all`].
+    pub async fn web_page_referents(
+        &self,
+        lookup: &WebPageLookup,
+        text_format: TextFormat,
+    ) -> Result<Vec<Referent>, GeniusError> {
+        let web_page = self.lookup_web_page(lookup).await?;
+        self.referents()
+            .web_page_id(web_page.id)
+            .text_format(text_format)
+            .collect_all()
+            .await
+    }
+}